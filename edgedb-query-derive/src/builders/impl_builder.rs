@@ -4,12 +4,14 @@ use syn::Ident;
 use quote::quote;
 use proc_macro2::TokenStream;
 use edgedb_query::QueryType;
-use crate::utils::derive_utils::{conflict_element_shape, conflict_element_value, nested_element_shape, nested_element_value};
+use crate::utils::derive_utils::{conflict_element_shape, conflict_element_value, nested_element_shape, nested_element_value, nested_many_element_value, prefixed_nested_element_shape};
 
 #[derive(Clone, PartialEq)]
 pub enum FieldCat {
     Simple(String),
     Nested,
+    PrefixedNested,
+    NestedMany(String),
     Conflict,
     Ignore,
 }
@@ -68,11 +70,13 @@ impl QueryImplBuilder {
                     use edgedb_query::EdgeResult;
 
                     let mut query = #query_str.to_owned();
+                    #[allow(unused_mut)]
+                    let mut query_type = edgedb_query::QueryType::from(#q_ty.to_string());
 
                     #(#stmts)*
 
                     edgedb_query::EdgeQl {
-                        query_type: edgedb_query::QueryType::from(#q_ty.to_string()),
+                        query_type,
                         table_name: #table_name.to_string(),
                         content: query,
                         has_result: #h_r
@@ -103,6 +107,8 @@ impl QueryImplBuilder {
                     match f.field_cat.clone() {
                         FieldCat::Simple(param) => f.field.field_shape_quote(param),
                         FieldCat::Nested => nested_element_shape(f.field.ident.clone()),
+                        FieldCat::PrefixedNested => prefixed_nested_element_shape(f.field.ident.clone()),
+                        FieldCat::NestedMany(param) => f.field.field_shape_quote(param),
                         FieldCat::Conflict => conflict_element_shape(f.field.ident.clone()),
                         _ => quote!()
                     }
@@ -111,7 +117,8 @@ impl QueryImplBuilder {
             let values = fields.clone()
                 .map(|f| {
                     match f.field_cat.clone() {
-                        FieldCat::Nested => nested_element_value(f.field.ident.clone()),
+                        FieldCat::Nested | FieldCat::PrefixedNested => nested_element_value(f.field.ident.clone()),
+                        FieldCat::NestedMany(_) => nested_many_element_value(f.field.ident.clone()),
                         FieldCat::Conflict => conflict_element_value(f.field.ident.clone()),
                         FieldCat::Simple(_) => f.field.field_value_quote(),
                         _ => quote!()
@@ -121,6 +128,11 @@ impl QueryImplBuilder {
             quote! {
             impl edgedb_query::ToEdgeValue for #struct_name {
                 fn to_edge_value(&self) -> edgedb_protocol::value::Value {
+                    self.try_to_edge_value().unwrap_or_else(|e| panic!("{}", e))
+                }
+
+                fn try_to_edge_value(&self) -> Result<edgedb_protocol::value::Value, edgedb_query::ValueOverflow> {
+                        use edgedb_query::ToEdgeValue;
                         use edgedb_query::queries::filter::Filter;
 
                         let mut fields: Vec<Option<edgedb_protocol::value::Value>> = vec![];
@@ -134,10 +146,10 @@ impl QueryImplBuilder {
 
                         let shape_slices: &[edgedb_protocol::descriptors::ShapeElement] = shapes.as_slice();
 
-                        edgedb_protocol::value::Value::Object {
+                        Ok(edgedb_protocol::value::Value::Object {
                             shape: edgedb_protocol::codec::ObjectShape::from(shape_slices),
                             fields,
-                        }
+                        })
                 }
             }
         }
@@ -9,15 +9,26 @@ pub const SUP_SIGN: &str = ">";
 pub const AT: &str = "@";
 pub const DOLLAR: &str = "$";
 pub const ID: &str= "id";
+pub const BASE: &str= "base";
+pub const UNIT_TYPE: &str = "()";
+pub const BYTES_SCALAR: &str = "<bytes>";
 // endregion other
 
 // region wrapper
 pub const OPTION: &str = "Option";
 pub const VEC: &str = "Vec";
+pub const BOX: &str = "Box";
+pub const LINK_TYPE: &str = "edgedb_query::Link";
+pub const LINKS_TYPE: &str = "edgedb_query::Links";
+pub const BYTES_TYPES: [&str; 2] = ["edgedb_query::Bytes", "bytes::Bytes"];
+pub const U8_TYPE: &str = "u8";
+pub const RANGE_TYPE: &str = "Range";
 // endregion
 
 // region query types
 pub const SELECT: &str = "select";
+pub const DELETE: &str = "delete";
+pub const BULK_ITEM: &str = "item";
 
 // endregion query types
 
@@ -28,6 +39,14 @@ pub const TABLE: &str = "table";
 pub const RESULT: &str = "result";
 pub const SRC: &str = "src";
 pub const ON: &str = "on";
+pub const ELSE: &str = "else";
+pub const UNLESS_CONFLICT_ELSE_TYPE_NAME: &str = "UnlessConflictElse";
+pub const OVERWRITE: &str = "overwrite";
+pub const EXTENDS: &str = "extends";
+pub const FREE_OBJECT: &str = "free_object";
+pub const VERSION: &str = "version";
+pub const EXPECTED_VERSION: &str = "expected_version";
+pub const NAME: &str = "name";
 // endregion metadata
 
 // region tags
@@ -35,6 +54,7 @@ pub const SCALAR: &str = "scalar";
 pub const SET: &str = "set";
 pub const SETS: &str = "sets";
 pub const OPTIONS: &str = "options";
+pub const SHAPE: &str = "shape";
 
 pub const FILTERS: &str = "filters";
 pub const FILTER: &str = "filter";
@@ -52,11 +72,17 @@ pub const FIELD: &str = "field";
 pub const WRAPPER_FN: &str = "wrapper_fn";
 pub const COLUMN_NAME: &str = "column_name";
 pub const DEFAULT_VALUE: &str = "default_value";
+pub const EXPR: &str = "expr";
 pub const SET_OPTION: &str = "option";
 pub const LINK_PROPERTY: &str = "link_property";
 
 pub const NESTED_QUERY: &str = "nested_query";
 
+pub const FLATTEN: &str = "flatten";
+
+pub const LINK: &str = "link";
+pub const DEPTH: &str = "depth";
+
 pub const LIMIT_1: &str = " limit 1";
 
 pub const UNLESS_CONFLICT: &str = "unless_conflict";
@@ -87,6 +113,8 @@ pub const SUP_OR_EQ_SIGN: &str = ">=";
 pub const LESSER_THAN: &str = "lesserthan";
 pub const LESSER_THAN_OR_EQUAL: &str = "lesserthanorequal";
 pub const INF_OR_EQ_SIGN: &str = "<=";
+pub const OVERLAPS: &str = "overlaps";
+pub const CONTAINS_VALUE: &str = "containsvalue";
 // endregion operators
 
 //region setOption
@@ -99,6 +127,9 @@ pub const PUSH: &str = "push";
 pub const PUSH_SIGN: &str = "+=";
 pub const REMOVE: &str = "remove";
 pub const REMOVE_SIGN: &str = "-=";
+pub const IF_NONE: &str = "if_none";
+pub const SKIP: &str = "skip";
+pub const CLEAR: &str = "clear";
 // endregion setOption
 
 // region patterns
@@ -123,16 +154,16 @@ pub const ONLY_ONE_KIND_OF_TAG_EXPECTED: &str = "Only one of the following tags
 
 pub const INVALID_INSERT_TAG: &str = r#"
     Invalid insert field tag.
-    Expected "field", "nested_query" or "unless_conflict"
+    Expected "field", "expr", "nested_query" or "unless_conflict"
 "#;
 pub const INVALID_FIELD_TAG: &str = r#"
     Invalid field tag option.
-    Expected "column_name" , "param", "scalar" or "link_property"
+    Expected "column_name" , "param", "scalar", "link_property" or "link"
 "#;
 
 pub const INVALID_UNLESS_CONFLICT_TAG: &str = r#"
     Invalid unless conflict tag option.
-    Expected "on"
+    Expected "on" or "else"
 "#;
 
 pub const INVALID_BACKLINK_TAG: &str = r#"
@@ -142,12 +173,12 @@ pub const INVALID_BACKLINK_TAG: &str = r#"
 
 pub const INVALID_SELECT_TAG: &str = r#"
     Invalid select field tag.
-    Expected "filter", "and_filter", "or_filter", "filters" or "options"
+    Expected "filter", "and_filter", "or_filter", "filters", "options" or "shape"
 "#;
 
 pub const INVALID_UPDATE_TAG: &str = r#"
     Invalid update field tag.
-    Expected "filter", "and_filter", "or_filter", "filters", "set" or "sets"
+    Expected "filter", "and_filter", "or_filter", "filters", "set", "sets" or "expr"
 "#;
 
 pub const INVALID_FILTER_TAG: &str = r#"
@@ -162,14 +193,39 @@ pub const INVALID_SET_OPTION: &str = r#"
 
 pub const INVALID_SET_TAG_OPTION: &str = r#"
     Invalid set tag option.
-    Expected "option"
+    Expected "option" or "if_none"
+"#;
+
+pub const INVALID_IF_NONE_OPTION: &str = r#"
+    Invalid if_none option.
+    Expected "skip" or "clear"
 "#;
 
 pub const INVALID_RESULT_FIELD_TAG: &str = r#"
     Invalid result's field tag.
-    Expected "field", "back_link" or "link_property"
+    Expected "field", "back_link", "link_property", "flatten" or "link"
 "#;
 
+pub const INVALID_RESULT_META: &str = r#"
+    Invalid query result attribute.
+    Expected "extends" or "free_object"
+"#;
+
+pub const INVALID_SCALAR_META: &str = r#"
+    Invalid edgedb scalar attribute.
+    Expected "name"
+"#;
+
+pub const EXPECT_SCALAR_NAME: &str = "edgedb_scalar expects the scalar type name : #[edgedb_scalar(name = \"...\")]";
+pub const NEWTYPE_EXPECTED: &str = "edgedb_scalar expects a newtype struct with a single unnamed field (e.g. `pub struct Email(String);`)";
+
+pub const INVALID_LINK_TAG: &str = r#"
+    Invalid link tag option.
+    Expected "depth"
+"#;
+
+pub const NAMED_TUPLE_FIELDS_EXPECTED: &str = "edgedb_tuple expects a struct with named fields";
+
 pub const INVALID_ENUM_VARIANT_TAG: &str = r#"
     Invalid enum's variant tag.
     Expected "value"
@@ -188,16 +244,22 @@ pub const INVALID_FILTERS_TAG: &str = r#"
 
 pub const INVALID_SETS_TAG: &str = r#"
     Invalid sets tag.
-    Expected "set"
+    Expected "set" or "expr"
 "#;
 
-pub const PUSH_OPTION_ONLY_FOR_VEC: &str = "Push option only accepts a Vec type";
-pub const REMOVE_OPTION_ONLY_FOR_VEC: &str = "Remove option only accepts a Vec type";
+pub const LINK_PROPERTY_TARGET_EXPECTED: &str = "A link property field expects the name of a Link or Links field of the query";
+pub const LINK_PROPERTY_ON_REMOVED_LINK: &str = "Link properties cannot be set on removed link targets";
+pub const IF_NONE_ONLY_FOR_OPTION: &str = "if_none option only accepts an Option type";
+pub const EXPR_PARAM_EXPECTED: &str = "Expected the expression to reference the field parameter, or the field to be of type ()";
+pub const EXPR_ON_LINK: &str = "expr attribute cannot be used on a Link, a Links or a link property field";
+pub const PUSH_OPTION_ONLY_FOR_VEC: &str = "Push option only accepts a Vec or a Links type";
+pub const REMOVE_OPTION_ONLY_FOR_VEC: &str = "Remove option only accepts a Vec or a Links type";
 
 pub const EXPECT_LIT_OR_NAMED_LIT: &str = "Expected a literal or a named string literal";
 pub const EXPECT_NAMED_LIT: &str = "Expected a named string literal";
 pub const EXPECT_LIT_STR: &str = "Expected a string literal";
 pub const EXPECT_LIT_BOOL: &str = "Expected a boolean literal";
+pub const EXPECT_LIT_INT: &str = "Expected an integer literal";
 
 pub const INVALID_TYPE_TUPLE_FOR_OPERATOR: &str = "Invalid type () for operator";
 
@@ -205,18 +267,37 @@ pub const ONLY_TYPE_FOR_OPERATOR: &str = " operator only accepts a type ";
 
 pub const INVALID_OPERATOR: &str = r#"
     Invalid operator.
-    Expected "Exists", "NotExists", "Is", "IsNot", "Like", "ILike", "In", "NotIn", "GreaterThan", "GreaterThanOrEqual", "LesserThan", "LesserThanOrEqual"
+    Expected "Exists", "NotExists", "Is", "IsNot", "Like", "ILike", "In", "NotIn", "GreaterThan", "GreaterThanOrEqual", "LesserThan", "LesserThanOrEqual", "Overlaps", "ContainsValue"
  "#;
 
+pub const RANGE_BOUND_SCALARS: [&str; 8] = ["int32", "int64", "float32", "float64", "decimal", "datetime", "cal::local_datetime", "cal::local_date"];
+pub const INVALID_RANGE_BOUND: &str = "Range bound type must be one of int32, int64, float32, float64, decimal, datetime, cal::local_datetime or cal::local_date, found";
+pub const CONTAINS_VALUE_ON_RANGE: &str = "ContainsValue operator expects a range bound value, not a range (use Overlaps to compare two ranges)";
+
 
 pub const EXPECTED_ONLY_TAGS: &str = "Expected only the following tags";
 pub const ONLY_ONE_OPTIONS_TAG_EXPECTED: &str = "SelectQuery can only have one options field";
+pub const ONLY_ONE_SHAPE_TAG_EXPECTED: &str = "SelectQuery can only have one shape field";
+pub const SHAPE_EXPECTS_RESULT: &str = "SelectQuery with a shape field expects a result type : #[select_query(result = \"...\")]";
 pub const ONLY_ONE_FILTERS_TAG_EXPECTED: &str = "SelectQuery can only have one filters field";
 pub const ONLY_ONE_SETS_TAG_EXPECTED: &str = "UpdateQuery can only have one sets field";
 pub const EITHER_ONE_FILTERS_OR_FILTER_TAG_EXPECTED: &str = "SelectQuery can only have either one `filters` or one or more `filter` fields";
 pub const EITHER_ONE_SETS_OR_SET_TAG_EXPECTED: &str = "UpdateQuery can only have either one `sets` or one or more `set` or `nested_query` fields";
+pub const VERSION_RESERVED_FIELD: &str = "UpdateQuery with a version cannot have a field or parameter named `expected_version`, it is added by the macro";
+pub const VERSION_WITH_FILTERS: &str = "UpdateQuery with a version expects #[filter] fields, not a `filters` field";
 pub const EXPECTED_AT_LEAST_ONE_SET_FIELD: &str = "UpdateQuery must have at least one field with #[set] attribute or with no attribute";
-pub const EXPECTED_ID_FIELD: &str = "Query result struct must have an id field of type uuid::Uuid";
+pub const EXPECTED_ID_FIELD: &str = "Query result struct must have an id field of type uuid::Uuid (or be declared with `free_object = true`)";
+pub const FREE_OBJECT_SELECT_EXPECTS_RESULT: &str = "SelectQuery without table must have a `result` free object";
+pub const FREE_OBJECT_SELECT_NO_FILTER: &str = "SelectQuery without table can neither have filters nor options";
+pub const EXPECT_UPSERT_ON: &str = "UpsertQuery expects the conflict key fields : #[upsert_query(table = \"...\", on = \"...\")]";
+pub const UPSERT_NO_UNLESS_CONFLICT: &str = "UpsertQuery cannot have an unless_conflict field, its conflict key fields are given by the `on` argument";
+pub const UPSERT_NOTHING_TO_OVERWRITE: &str = "UpsertQuery has no field to overwrite on conflict";
+pub const INVALID_UNLESS_CONFLICT_ELSE: &str = "Invalid unless conflict else option. Expected \"select\"";
+pub const UNLESS_CONFLICT_ELSE_TYPE: &str = "#[unless_conflict(else = \"select\")] expects a field of type UnlessConflict, the else query is generated";
+pub const EXTENDS_RESERVED_BASE: &str = "extends attribute stores the extended result in a `base` field, the struct cannot declare a field named `base`";
+pub const FLATTEN_ONLY_ALONE: &str = "#[flatten] attribute cannot be combined with other field attributes";
+pub const EXPECTED_LINK_DEPTH: &str = "#[link] attribute expects a depth greater than 0 : #[link(depth = N)]";
+pub const LINK_ONLY_SELF_REFERENCE: &str = "#[link(depth)] attribute only accepts a self-referential field of type Vec<Self> or Option<Box<Self>>";
 pub const AT_LEAST_ONE_FIELD_ATTRIBUTE_EXPECTED: &str = "At least one field attribute is expected: 'column_name', 'param' or  'scalar'";
 // endregion messages
 
@@ -1,17 +1,23 @@
+use std::convert::TryFrom;
+
 use edgedb_query::QueryType;
-use syn::{Ident, ItemStruct};
+use quote::{quote, ToTokens};
+use syn::{Field, Ident, ItemStruct};
 use syn::parse::{Parse, ParseStream};
 
-use crate::{meta_data::{TableInfo, try_get_meta}, queries::Query};
-use crate::builders::impl_builder::QueryImplBuilder;
+use crate::{meta_data::{QueryMetaData, try_get_meta}, queries::Query};
+use crate::builders::impl_builder::{FieldCat, ImplBuilderField, QueryImplBuilder};
 use crate::constants::*;
+use crate::select_query::SelectOptions;
 use crate::statements::filters::{FilterRequiredQuery, filters_from_fields, FilterStatement, set_table_name};
+use crate::utils::attributes_utils::has_attribute;
 
 #[derive(Debug, Clone)]
 pub struct DeleteQuery {
     pub ident: Ident,
-    pub meta: Option<TableInfo>,
+    pub meta: Option<QueryMetaData>,
     pub filter_statement: FilterStatement,
+    pub options: Option<SelectOptions>,
 }
 
 impl DeleteQuery {
@@ -20,10 +26,11 @@ impl DeleteQuery {
             ident,
             meta: None,
             filter_statement: FilterStatement::NoFilter,
+            options: None,
         }
     }
 
-    pub fn with_meta(&mut self, meta: TableInfo) -> &mut Self {
+    pub fn with_meta(&mut self, meta: QueryMetaData) -> &mut Self {
         self.meta = Some(meta.clone());
         set_table_name(&mut self.filter_statement, meta.table_name());
         self
@@ -40,20 +47,51 @@ impl Query for DeleteQuery {
 
         let table_name = meta.table_name();
 
-        let fields =  self.filter_statement.to_impl_builder_field();
+        let has_result = meta.has_result();
+
+        let mut fields =  self.filter_statement.to_impl_builder_field();
 
         let mut edgeql_statements = vec![];
 
+        // with options, the deleted objects are selected first : delete (select T filter ... order by ... limit n)
+        let query_type = if self.options.is_some() {
+            let select_stmt = format!("{DELETE} ({SELECT} {table_name} ");
+            edgeql_statements.push(quote!(query.push_str(#select_stmt);));
+            QueryType::None
+        } else {
+            QueryType::Delete
+        };
+
         edgeql_statements.extend(self.filter_statement.edgeql_statements(table_name.clone(), false));
 
+        let static_const_check_statements = if let Some(options) = self.options.clone() {
+            fields.push(ImplBuilderField {
+                field: options.field.clone(),
+                field_cat: FieldCat::Ignore,
+            });
+
+            edgeql_statements.push(options.unchecked_statement_quote(table_name.clone()));
+            edgeql_statements.push(quote!(query.push_str(")");));
+
+            vec![options.const_check_impl_quote()]
+        } else {
+            vec![]
+        };
+
+        if has_result {
+            edgeql_statements.push(quote! { query.push_str(" )"); });
+        }
+
+        edgeql_statements.push(meta.result_quote());
+
         Ok(QueryImplBuilder {
             struct_name: self.ident.clone(),
             table_name: Some(table_name.clone()),
             fields,
-            query_type: QueryType::Delete,
-            static_const_check_statements: vec![],
+            query_type,
+            static_const_check_statements,
             edgeql_statements,
-            has_result: false
+            has_result
         })
     }
 }
@@ -69,8 +107,24 @@ impl Parse for DeleteQuery {
         let field_iter = strukt.fields.iter();
         // endregion create new query
 
+        // region handle options field if exists
+        let options_fields: Vec<&Field> = field_iter.clone()
+            .filter(|f| has_attribute(f, OPTIONS))
+            .collect::<Vec<&Field>>();
+
+        match options_fields.len() {
+            0 => {}
+            1 => {
+                query.options = Some(SelectOptions::try_from(options_fields[0])?);
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(options_fields[1].to_token_stream(), ONLY_ONE_OPTIONS_TAG_EXPECTED));
+            }
+        }
+        // endregion handle options field if exists
+
         // region add filters statements
-        query.filter_statement = filters_from_fields(field_iter, vec![], FilterRequiredQuery::Delete,INVALID_DELETE_TAG)?;
+        query.filter_statement = filters_from_fields(field_iter, vec![OPTIONS], FilterRequiredQuery::Delete,INVALID_DELETE_TAG)?;
         // endregion add filters statements
 
         query.check_duplicate_parameter_labels()?;
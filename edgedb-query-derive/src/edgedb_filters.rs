@@ -48,6 +48,10 @@ impl EdgedbFilters {
 
 
                 fn to_edge_value(&self) -> edgedb_protocol::value::Value {
+                    edgedb_query::queries::filter::Filter::try_to_edge_value(self).unwrap_or_else(|e| panic!("{}", e))
+                }
+
+                fn try_to_edge_value(&self) -> Result<edgedb_protocol::value::Value, edgedb_query::ValueOverflow> {
                     use edgedb_query::ToEdgeValue;
 
                     let mut fields: Vec<Option<edgedb_protocol::value::Value>> = vec![];
@@ -64,12 +68,12 @@ impl EdgedbFilters {
 
                     #values
 
-                    edgedb_protocol::value::Value::Object {
+                    Ok(edgedb_protocol::value::Value::Object {
 
                         shape: edgedb_protocol::codec::ObjectShape::from(shape_slices),
 
                         fields,
-                    }
+                    })
                 }
             }
         };
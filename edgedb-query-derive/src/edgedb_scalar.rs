@@ -0,0 +1,101 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Fields, ItemStruct};
+use syn::parse::{Parse, ParseStream};
+use crate::constants::NEWTYPE_EXPECTED;
+use crate::meta_data::ScalarMeta;
+
+pub struct EdgedbScalar {
+    pub strukt: ItemStruct,
+    pub meta: Option<ScalarMeta>,
+}
+
+impl EdgedbScalar {
+    pub fn with_meta(&mut self, meta: ScalarMeta) -> syn::Result<&mut Self> {
+        self.meta = Some(meta);
+        Ok(self)
+    }
+
+    pub fn to_token_stream(&self) -> syn::Result<TokenStream> {
+
+        let strukt = &self.strukt;
+
+        let struct_name = strukt.ident.clone();
+
+        let inner_ty = match &strukt.fields {
+            Fields::Unnamed(fields) => fields.unnamed[0].ty.clone(),
+            _ => unreachable!()
+        };
+
+        let name = self.meta.as_ref().map(|m| m.name.clone()).unwrap_or_default();
+
+        let scalar = format!("<{name}>");
+
+        let tokens = quote! {
+
+            #strukt
+
+            impl edgedb_query::ToEdgeScalar for #struct_name {
+                fn scalar() -> String {
+                    #scalar.to_owned()
+                }
+            }
+
+            impl edgedb_query::EdgedbScalar for #struct_name {}
+
+            impl edgedb_query::ToEdgeValue for #struct_name {
+                fn to_edge_value(&self) -> edgedb_protocol::value::Value {
+                    use edgedb_query::ToEdgeValue;
+                    self.0.to_edge_value()
+                }
+
+                fn try_to_edge_value(&self) -> Result<edgedb_protocol::value::Value, edgedb_query::ValueOverflow> {
+                    use edgedb_query::ToEdgeValue;
+                    self.0.try_to_edge_value()
+                }
+            }
+
+            impl edgedb_query::ToEdgeQl for #struct_name {
+                fn to_edgeql(&self) -> edgedb_query::EdgeQl {
+                    use edgedb_query::ToEdgeQl;
+                    self.0.to_edgeql()
+                }
+            }
+
+            impl edgedb_query::ToEdgeShape for #struct_name {
+                fn shape() -> String {
+                    String::default()
+                }
+            }
+
+            impl edgedb_query::EdgeResult for #struct_name {
+                fn returning_fields() -> Vec<&'static str> {
+                    vec![]
+                }
+            }
+
+            impl edgedb_protocol::queryable::Queryable for #struct_name {
+                fn decode(decoder: &edgedb_protocol::queryable::Decoder, buf: &[u8]) -> Result<Self, edgedb_protocol::errors::DecodeError> {
+                    <#inner_ty as edgedb_protocol::queryable::Queryable>::decode(decoder, buf).map(Self)
+                }
+
+                fn check_descriptor(ctx: &edgedb_protocol::queryable::DescriptorContext, type_pos: edgedb_protocol::descriptors::TypePos) -> Result<(), edgedb_protocol::queryable::DescriptorMismatch> {
+                    <#inner_ty as edgedb_protocol::queryable::Queryable>::check_descriptor(ctx, type_pos)
+                }
+            }
+        };
+
+        Ok(tokens.into())
+    }
+}
+
+impl Parse for EdgedbScalar {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let strukt = input.parse::<ItemStruct>()?;
+
+        match &strukt.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Ok(Self { strukt, meta: None }),
+            _ => Err(syn::Error::new_spanned(strukt, NEWTYPE_EXPECTED))
+        }
+    }
+}
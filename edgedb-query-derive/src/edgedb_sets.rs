@@ -79,6 +79,10 @@ impl EdgedbSets {
                 }
 
                 fn to_edge_value(&self) -> edgedb_protocol::value::Value {
+                    edgedb_query::queries::set::Sets::try_to_edge_value(self).unwrap_or_else(|e| panic!("{}", e))
+                }
+
+                fn try_to_edge_value(&self) -> Result<edgedb_protocol::value::Value, edgedb_query::ValueOverflow> {
                     use edgedb_query::{ToEdgeValue};
 
                     let mut fields: Vec<Option<edgedb_protocol::value::Value>> = vec![];
@@ -88,10 +92,10 @@ impl EdgedbSets {
                     #shapes
                     let shape_slices: &[edgedb_protocol::descriptors::ShapeElement] = shapes.as_slice();
                     #values
-                    edgedb_protocol::value::Value::Object {
+                    Ok(edgedb_protocol::value::Value::Object {
                         shape: edgedb_protocol::codec::ObjectShape::from(shape_slices),
                         fields,
-                    }
+                    })
                 }
             }
         };
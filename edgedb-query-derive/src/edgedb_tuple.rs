@@ -0,0 +1,152 @@
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{Attribute, Fields, ItemStruct, Type, Visibility};
+use syn::parse::{Parse, ParseStream};
+use crate::constants::NAMED_TUPLE_FIELDS_EXPECTED;
+
+pub struct EdgedbTuple {
+    pub ident: Ident,
+    pub vis: Visibility,
+    pub attrs: Vec<Attribute>,
+    pub elements: Vec<EdgedbTupleElement>,
+}
+
+impl EdgedbTuple {
+    pub fn to_token_stream(&self) -> syn::Result<TokenStream> {
+
+        let struct_name = self.ident.clone();
+        let vis = self.vis.clone();
+        let attrs = self.attrs.iter();
+
+        let fields_quote = self.elements.iter().map(|e| {
+            let EdgedbTupleElement { attrs, vis, ident, ty } = e;
+            quote!(#(#attrs)* #vis #ident: #ty,)
+        });
+
+        let names = self.elements.iter().map(|e| e.ident.to_string()).collect::<Vec<String>>();
+
+        let nb_elements = self.elements.len();
+
+        let element_scalars = self.elements.iter().map(|e| {
+            let name = e.ident.to_string();
+            let ty = e.ty.clone();
+            quote!(format!("{}: {}", #name, edgedb_query::scalar_name::<#ty>()))
+        });
+
+        let element_values = self.elements.iter().map(|e| {
+            let ident = e.ident.clone();
+            quote!(self.#ident.try_to_edge_value()?)
+        });
+
+        let decode_elements = self.elements.iter().map(|e| {
+            let ident = e.ident.clone();
+            quote!(#ident: edgedb_protocol::queryable::Queryable::decode_optional(decoder, elements.read()?)?)
+        });
+
+        let check_elements = self.elements.iter().enumerate().map(|(i, e)| {
+            let ty = e.ty.clone();
+            quote!(<#ty as edgedb_protocol::queryable::Queryable>::check_descriptor(ctx, elements[#i])?;)
+        });
+
+        let tokens = quote! {
+
+            #(#attrs)*
+            #[derive(Debug, Clone, Default)]
+            #vis struct #struct_name {
+                #(#fields_quote)*
+            }
+
+            impl edgedb_query::ToEdgeScalar for #struct_name {
+                fn scalar() -> String {
+                    let elements: Vec<String> = vec![#(#element_scalars),*];
+                    format!("<tuple<{}>>", elements.join(", "))
+                }
+            }
+
+            impl edgedb_query::ToEdgeValue for #struct_name {
+                fn to_edge_value(&self) -> edgedb_protocol::value::Value {
+                    use edgedb_query::ToEdgeValue;
+                    self.try_to_edge_value().unwrap_or_else(|e| panic!("{}", e))
+                }
+
+                fn try_to_edge_value(&self) -> Result<edgedb_protocol::value::Value, edgedb_query::ValueOverflow> {
+                    use edgedb_query::ToEdgeValue;
+                    Ok(edgedb_query::models::tuple::named_tuple_value(&[#(#names),*], vec![#(#element_values),*]))
+                }
+            }
+
+            impl edgedb_query::ToEdgeQl for #struct_name {
+                fn to_edgeql(&self) -> edgedb_query::EdgeQl {
+                    edgedb_query::EdgeQl::default()
+                }
+            }
+
+            impl edgedb_query::ToEdgeShape for #struct_name {
+                fn shape() -> String {
+                    String::default()
+                }
+            }
+
+            impl edgedb_query::EdgeResult for #struct_name {
+                fn returning_fields() -> Vec<&'static str> {
+                    vec![]
+                }
+            }
+
+            impl edgedb_protocol::queryable::Queryable for #struct_name {
+                fn decode(decoder: &edgedb_protocol::queryable::Decoder, buf: &[u8]) -> Result<Self, edgedb_protocol::errors::DecodeError> {
+                    let mut elements = edgedb_query::models::tuple::open_named_tuple(buf, #nb_elements)?;
+                    Ok(Self {
+                        #(#decode_elements,)*
+                    })
+                }
+
+                fn check_descriptor(ctx: &edgedb_protocol::queryable::DescriptorContext, type_pos: edgedb_protocol::descriptors::TypePos) -> Result<(), edgedb_protocol::queryable::DescriptorMismatch> {
+                    let elements = edgedb_query::models::tuple::check_named_tuple_descriptor(ctx, type_pos, &[#(#names),*])?;
+                    #(#check_elements)*
+                    Ok(())
+                }
+            }
+        };
+
+        Ok(tokens.into())
+    }
+}
+
+impl Parse for EdgedbTuple {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let strukt = input.parse::<ItemStruct>()?;
+
+        let Fields::Named(fields) = &strukt.fields else {
+            return Err(syn::Error::new_spanned(strukt, NAMED_TUPLE_FIELDS_EXPECTED));
+        };
+
+        if fields.named.is_empty() {
+            return Err(syn::Error::new_spanned(strukt, NAMED_TUPLE_FIELDS_EXPECTED));
+        }
+
+        let elements = fields.named.iter()
+            .map(|f| EdgedbTupleElement {
+                attrs: f.attrs.clone(),
+                vis: f.vis.clone(),
+                ident: f.ident.clone().unwrap(),
+                ty: f.ty.clone(),
+            })
+            .collect();
+
+        Ok(Self {
+            ident: strukt.ident,
+            vis: strukt.vis,
+            attrs: strukt.attrs,
+            elements,
+        })
+    }
+}
+
+pub struct EdgedbTupleElement {
+    pub attrs: Vec<Attribute>,
+    pub vis: Visibility,
+    pub ident: Ident,
+    pub ty: Type,
+}
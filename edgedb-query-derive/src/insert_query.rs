@@ -1,22 +1,25 @@
 use std::convert::TryFrom;
 
+use proc_macro::TokenStream;
 use quote::{quote, ToTokens};
 use syn::{Field, Ident, ItemStruct};
 use syn::parse::{Parse, ParseStream};
 use edgedb_query::QueryType;
+use edgedb_query::queries::bulk::json_item_cast;
 
-use crate::constants::{EDGEQL, FIELD, INVALID_INSERT_TAG, NESTED_QUERY, OPTION, SCALAR_TYPE, SELECT, UNLESS_CONFLICT, VEC};
+use crate::constants::{BULK_ITEM, EDGEQL, EXPR, FIELD, INVALID_INSERT_TAG, LINK_PROPERTY_TARGET_EXPECTED, NESTED_QUERY, OPTION, SCALAR_TYPE, SELECT, UNIT_TYPE, UNLESS_CONFLICT};
 use crate::queries::{Query, QueryField};
 use crate::meta_data::{QueryMetaData, try_get_meta};
 use crate::builders::impl_builder::{FieldCat, QueryImplBuilder, ImplBuilderField};
 use crate::statements::nested_query::{NestedQueryField, NestedQueryParentType};
 use crate::tags::{build_tags_from_field, Tagged};
 use crate::tags::field_tag::{FieldTag, FieldTagBuilder};
-use crate::tags::TagBuilders::{FieldBuilder, UnlessConfictBuilder};
-use crate::tags::unless_conflict_tag::{UnlessConflictTag, UnlessConflictTagBuilder};
-use crate::utils::attributes_utils::has_attribute;
-use crate::utils::derive_utils::format_scalar;
-use crate::utils::type_utils::{get_type, is_type_name};
+use crate::tags::TagBuilders::{ExprBuilder, FieldBuilder, UnlessConfictBuilder};
+use crate::tags::expr_tag::{ExprTag, ExprTagBuilder};
+use crate::tags::unless_conflict_tag::{is_conflict_else_type, UnlessConflictTag, UnlessConflictTagBuilder};
+use crate::utils::attributes_utils::{has_any_attribute, has_attribute};
+use crate::utils::derive_utils::{custom_scalar_check_quote, link_target_quote, resolved_statement_quote, scalar_quote};
+use crate::utils::type_utils::{get_type, is_bytes_type, is_link_type, is_range_type, is_type_name};
 
 // region InsertQuery
 #[derive(Debug, Clone)]
@@ -42,16 +45,11 @@ impl InsertQuery {
         self.statements.iter_mut().for_each(|s |s.set_parent_table_name(meta.table_name()));
         self
     }
-}
 
-impl Query for InsertQuery {
-    fn get_param_labels(&self) -> Vec<(Ident, String)> {
-        self.statements.iter().clone()
-            .filter_map(|stmt| stmt.param_field())
-            .collect()
-    }
-
-    fn to_impl_builder(&self) -> syn::Result<QueryImplBuilder> {
+    /// Build the insert query implementation
+    ///
+    /// __conflict_quote__ : the conflict statement to use instead of the unless_conflict field one (upsert query)
+    pub fn impl_builder_with_conflict(&self, conflict_quote: Option<proc_macro2::TokenStream>) -> syn::Result<QueryImplBuilder> {
         let meta = try_get_meta(&self.ident.clone(), || self.meta.clone())?;
 
         let table_name = meta.table_name();
@@ -63,10 +61,22 @@ impl Query for InsertQuery {
         let mut fields: Vec<ImplBuilderField> = stmts.clone()
             .map(|stmt| {
                 match stmt {
+                    InsertStatement::SimpleField(f) if !f.has_param() => ImplBuilderField {
+                        field: f.field.clone(),
+                        field_cat: FieldCat::Ignore
+                    },
                     InsertStatement::SimpleField(f) => ImplBuilderField {
                         field: f.field.clone(),
                         field_cat: FieldCat::Simple(f.tag.parameter_label.clone())
                     },
+                    InsertStatement::NestedQuery(f) if f.is_many() => ImplBuilderField {
+                        field: f.field.clone(),
+                        field_cat: FieldCat::NestedMany(f.field.ident.to_string())
+                    },
+                    InsertStatement::NestedQuery(f) if f.is_optional() => ImplBuilderField {
+                        field: f.field.clone(),
+                        field_cat: FieldCat::PrefixedNested
+                    },
                     InsertStatement::NestedQuery(f) => ImplBuilderField {
                         field: f.field.clone(),
                         field_cat: FieldCat::Nested
@@ -87,7 +97,9 @@ impl Query for InsertQuery {
                 field_cat: FieldCat::Conflict
             });
 
-            edgeql_statements.push(uce.query_statement_quote())
+            edgeql_statements.push(uce.query_statement_quote(table_name.clone()))
+        } else if let Some(conflict_quote) = conflict_quote {
+            edgeql_statements.push(conflict_quote)
         }
 
         if has_result {
@@ -112,6 +124,132 @@ impl Query for InsertQuery {
             has_result
         })
     }
+
+    /// Attach each link property field (#[field(link = "...")]) to the Link or Links field it belongs to
+    pub fn attach_link_properties(&mut self) -> syn::Result<()> {
+        let properties = self.statements.iter()
+            .filter_map(|stmt| match stmt {
+                InsertStatement::SimpleField(f) => f.tag.link.clone().map(|link| (link, f.clone())),
+                InsertStatement::NestedQuery(_) => None,
+            })
+            .collect::<Vec<(String, InsertField)>>();
+
+        for (link, property) in properties {
+            let target = self.statements.iter_mut()
+                .find_map(|stmt| match stmt {
+                    InsertStatement::SimpleField(f) if f.is_link() && f.field.ident == link.as_str() => Some(f),
+                    _ => None,
+                });
+
+            let Some(target) = target else {
+                return Err(syn::Error::new_spanned(&property.field.ident, format!("{LINK_PROPERTY_TARGET_EXPECTED} : `{link}`")));
+            };
+
+            target.properties.push((
+                property.field.ident.clone(),
+                is_type_name(&property.field.ty, OPTION),
+                resolved_statement_quote(property.tag.link_property_statement(), &property.field.ty),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Build the bulk insert implementation of an insert query without nested queries, links, bytes, ranges
+    /// nor unless conflict else query : each item is read from the __item__ json element,
+    /// which is also bound to the parameter of an expression
+    fn bulk_impl_quote(&self, meta: &QueryMetaData) -> proc_macro2::TokenStream {
+        // an else query is built from one item and its parameters are not part of the items
+        if let Some(uce) = &self.unless_conflict_statement {
+            if !uce.tag.else_select && is_conflict_else_type(&uce.field.ty) {
+                return quote!();
+            }
+        }
+
+        let insert_fields = self.statements.iter()
+            .map(|stmt| match stmt {
+                InsertStatement::SimpleField(f) if is_bytes_type(&f.field.ty) || is_range_type(&f.field.ty) => None,
+                InsertStatement::SimpleField(f) if !f.is_link() && f.tag.link.is_none() => Some(f),
+                _ => None,
+            })
+            .collect::<Option<Vec<&InsertField>>>();
+
+        let Some(insert_fields) = insert_fields else {
+            return quote!();
+        };
+
+        let struct_name = &self.ident;
+
+        let insert_stmt = format!("insert {} ", meta.table_name());
+
+        let field_stmts = insert_fields.iter().map(|f| f.bulk_statement_quote());
+
+        let field_values = insert_fields.iter().map(|f| f.bulk_value_quote());
+
+        let conflict_stmt = self.unless_conflict_statement.as_ref()
+            .map(|uce| uce.bulk_statement_quote(meta.table_name()))
+            .unwrap_or_default();
+
+        let result_stmt = meta.result_quote();
+
+        quote! {
+            impl edgedb_query::queries::bulk::BulkInsert for #struct_name {
+                fn item_edgeql(item: Option<&Self>) -> String {
+                    use edgedb_query::ToEdgeScalar;
+
+                    let mut query = #insert_stmt.to_owned();
+                    query.push_str("{");
+                    #(#field_stmts)*
+                    query.push_str("}");
+                    #conflict_stmt
+                    query
+                }
+
+                fn bulk_edgeql(item: Option<&Self>) -> String {
+                    let insert = Self::item_edgeql(item);
+
+                    let mut query = String::new();
+                    #result_stmt
+
+                    edgedb_query::queries::bulk::bulk_insert_statement(insert.as_str(), query.as_str())
+                }
+
+                fn bulk_values(&self) -> Result<Vec<(String, edgedb_protocol::value::Value)>, edgedb_query::ValueOverflow> {
+                    use edgedb_query::ToEdgeValue;
+
+                    let mut values = vec![];
+                    #(#field_values)*
+                    Ok(values)
+                }
+            }
+        }
+    }
+}
+
+impl Query for InsertQuery {
+    fn get_param_labels(&self) -> Vec<(Ident, String)> {
+        self.statements.iter().clone()
+            .filter_map(|stmt| stmt.param_field())
+            .collect()
+    }
+
+    fn to_impl_builder(&self) -> syn::Result<QueryImplBuilder> {
+        self.impl_builder_with_conflict(None)
+    }
+
+    fn to_token_stream(&self) -> syn::Result<TokenStream> {
+        let meta = try_get_meta(&self.ident.clone(), || self.meta.clone())?;
+
+        let query_quote = self.to_impl_builder()?.build();
+
+        let bulk_quote = self.bulk_impl_quote(&meta);
+
+        Ok(quote! {
+            #query_quote
+
+            #bulk_quote
+        }.into())
+    }
 }
 
 impl Parse for InsertQuery {
@@ -150,6 +288,8 @@ impl Parse for InsertQuery {
             }
         }
 
+        query.attach_link_properties()?;
+
         let column_names = query.statements
             .iter()
             .filter_map(|stmt| stmt.column_name())
@@ -179,11 +319,20 @@ impl InsertStatement {
 
     pub fn param_field(&self) -> Option<(Ident, String)> {
         match self {
+            InsertStatement::SimpleField(f) if !f.has_param() => None,
             InsertStatement::SimpleField(f) => Some((f.field.ident.clone(), f.tag.parameter_label.clone())),
+            InsertStatement::NestedQuery(f) if f.is_many() => Some((f.field.ident.clone(), f.field.ident.to_string())),
             InsertStatement::NestedQuery(_) => None
         }
     }
 
+    pub fn field_ident(&self) -> &Ident {
+        match self {
+            InsertStatement::SimpleField(f) => &f.field.ident,
+            InsertStatement::NestedQuery(f) => &f.field.ident
+        }
+    }
+
     pub fn column_name(&self) -> Option<String> {
         match self {
             InsertStatement::SimpleField(f) => Some(f.tag.column_name.clone()),
@@ -211,7 +360,7 @@ impl TryFrom<&Field> for InsertStatement {
     fn try_from(field: &Field) -> Result<Self, Self::Error> {
         if has_attribute(field, NESTED_QUERY) {
             Ok(InsertStatement::NestedQuery(NestedQueryField::try_from((field, NestedQueryParentType::Query))?))
-        } else if has_attribute(field, FIELD) || field.attrs.is_empty() {
+        } else if has_any_attribute(field, vec![FIELD, EXPR]) || field.attrs.is_empty() {
             Ok(InsertStatement::SimpleField(InsertField::try_from(field)?))
         } else {
             Err(syn::Error::new_spanned(field.to_token_stream(), INVALID_INSERT_TAG))
@@ -226,10 +375,16 @@ impl TryFrom<&Field> for InsertStatement {
 pub struct InsertField {
     pub field: QueryField,
     pub tag: FieldTag,
+    pub expr: Option<ExprTag>,
+    pub properties: Vec<(Ident, bool, proc_macro2::TokenStream)>,
 }
 
 impl InsertField {
     pub fn build_statement(&self) -> String {
+        if let Some(ExprTag(expr)) = &self.expr {
+            return format!("{} := ({expr}), ", self.tag.column_name);
+        }
+
         format!(
             "{column_name} := ({select} {edge_type}${param}), ",
             select = SELECT,
@@ -239,24 +394,127 @@ impl InsertField {
         )
     }
 
+    pub fn build_bulk_statement(&self) -> String {
+        let item = if is_type_name(&self.field.ty, OPTION) {
+            format!("json_get({BULK_ITEM}, '{}')", self.tag.parameter_label)
+        } else {
+            format!("{BULK_ITEM}['{}']", self.tag.parameter_label)
+        };
+
+        let value = format!("{edge_type}{item}", edge_type = json_item_cast(&self.tag.scalar_type));
+
+        // the expression parameter is bound to the item value
+        if let Some(expr) = &self.expr {
+            return format!("{} := ({}), ", self.tag.column_name, expr.replace_param(&self.tag.parameter_label, &format!("({value})")));
+        }
+
+        format!("{column_name} := ({value}), ", column_name = self.tag.column_name)
+    }
+
+    fn scalar_quote(&self) -> proc_macro2::TokenStream {
+        let scalar_quote = scalar_quote(&self.field.ty);
+
+        if self.tag.scalar_type == SCALAR_TYPE {
+            let custom_scalar_check = custom_scalar_check_quote(&self.field.ty);
+            quote!(#custom_scalar_check #scalar_quote)
+        } else {
+            scalar_quote
+        }
+    }
+
+    pub fn bulk_statement_quote(&self) -> proc_macro2::TokenStream {
+        if self.expr.is_some() && !self.has_param() {
+            let field_statement = self.build_statement();
+            return quote!(query.push_str(#field_statement););
+        }
+
+        let scalar_quote = self.scalar_quote();
+        let scalar_type = SCALAR_TYPE.to_string();
+        let field_statement = self.build_bulk_statement();
+
+        quote! {
+            #scalar_quote
+            let p = #field_statement.to_owned().replace(#scalar_type, scalar.as_str());
+            query.push_str(p.as_str());
+        }
+    }
+
+    pub fn bulk_value_quote(&self) -> proc_macro2::TokenStream {
+        if !self.has_param() {
+            return quote!();
+        }
+
+        let field_name = self.field.ident.clone();
+        let param = self.tag.parameter_label.clone();
+
+        if is_type_name(&self.field.ty, OPTION) {
+            quote! {
+                if let Some(v) = &self.#field_name {
+                    values.push((#param.to_owned(), v.try_to_edge_value()?));
+                }
+            }
+        } else {
+            quote! {
+                values.push((#param.to_owned(), self.#field_name.try_to_edge_value()?));
+            }
+        }
+    }
+
+    /// returns false when the field is of type (), i.e. an expression without parameter
+    pub fn has_param(&self) -> bool {
+        !is_type_name(&self.field.ty, UNIT_TYPE)
+    }
+
+    pub fn is_link(&self) -> bool {
+        is_link_type(&self.field.ty)
+    }
+
+    fn link_statement_quote(&self) -> proc_macro2::TokenStream {
+        let field_name = self.field.ident.clone();
+        let column_name = self.tag.column_name.clone();
+        let link_quote = link_target_quote(&get_type(&self.field.ty), self.tag.parameter_label.clone(), self.properties.clone());
+
+        let add_assignment_quote = quote! {
+            #link_quote
+            let p = format!("{} := {}, ", #column_name, link);
+            query.push_str(p.as_str());
+        };
+
+        if is_type_name(&self.field.ty, OPTION) {
+            quote! {
+                if self.#field_name.is_some() {
+                    #add_assignment_quote
+                }
+            }
+        } else {
+            add_assignment_quote
+        }
+    }
+
     pub fn query_statement_quote(&self) -> proc_macro2::TokenStream {
+        if self.is_link() {
+            return self.link_statement_quote();
+        }
+
+        // a link property is assigned within its link statement
+        if self.tag.link.is_some() {
+            return quote!();
+        }
+
+        if self.expr.is_some() {
+            return self.expr_statement_quote();
+        }
+
         let field_name = self.field.ident.clone();
-        let format_scalar = format_scalar();
         let scalar_type = SCALAR_TYPE.to_string();
         let edge_ql = EDGEQL.to_string();
         let field_statement = self.build_statement();
         let field_type = self.field.ty.clone();
-        let ty = get_type(&field_type);
 
-        let scalar_quote = if is_type_name(&field_type, VEC) {
-            quote! { let mut scalar = format!("<array{}>", #ty::scalar()); }
-        } else {
-            quote! { let mut scalar = #ty::scalar();}
-        };
+        let scalar_quote = self.scalar_quote();
 
         let add_assignment_quote = quote! {
             #scalar_quote
-            #format_scalar;
             let p = #field_statement.to_owned()
                 .replace(#scalar_type, scalar.as_str())
                 .replace(#edge_ql, edgeql.to_string().as_str());
@@ -279,19 +537,45 @@ impl InsertField {
     }
 }
 
+impl InsertField {
+    /// Build the assignment of a field's expression, the field's value being passed as the expression parameter
+    fn expr_statement_quote(&self) -> proc_macro2::TokenStream {
+        let field_name = self.field.ident.clone();
+        let field_statement = self.build_statement();
+
+        if is_type_name(&self.field.ty, OPTION) {
+            quote! {
+                if self.#field_name.is_some() {
+                    query.push_str(#field_statement);
+                }
+            }
+        } else {
+            quote! {
+                query.push_str(#field_statement);
+            }
+        }
+    }
+}
+
 impl TryFrom<&Field> for InsertField {
     type Error = syn::Error;
 
     fn try_from(field: &Field) -> Result<Self, Self::Error> {
         let mut builders = FieldBuilder(FieldTagBuilder::default());
+        let mut expr_builders = ExprBuilder(ExprTagBuilder::default());
 
-        build_tags_from_field(&Tagged::StructField(field.clone()), vec![&mut builders])?;
+        build_tags_from_field(&Tagged::StructField(field.clone()), vec![&mut builders, &mut expr_builders])?;
 
         let field_tag_builder: FieldTagBuilder = builders.into();
+        let expr_tag_builder: ExprTagBuilder = expr_builders.into();
+
+        let tag = field_tag_builder.build(field)?;
 
         Ok(Self {
-            field: QueryField::try_from((field, vec![FIELD]))?,
-            tag: field_tag_builder.build(field)?,
+            field: QueryField::try_from((field, vec![FIELD, EXPR]))?,
+            expr: expr_tag_builder.build(field, &tag.parameter_label, tag.link.is_some())?,
+            tag,
+            properties: vec![],
         })
     }
 }
@@ -316,20 +600,43 @@ impl UnlessConflictElseStatement {
         }
     }
 
-    pub fn query_statement_quote(&self) -> proc_macro2::TokenStream {
+    fn conflict_quote(&self, source: proc_macro2::TokenStream, table_name: String) -> proc_macro2::TokenStream {
         let f_name = self.field.ident.clone();
         let on_fields_name = self.tag.on.iter()
             .map(|f| f.to_string())
             .collect::<Vec<String>>().join(",");
 
+        let parse_quote = if self.tag.else_select {
+            quote!(edgedb_query::queries::conflict::parse_conflict_else_select(qn, #table_name))
+        } else {
+            quote!(edgedb_query::queries::conflict::parse_conflict(&#source.#f_name, qn))
+        };
 
         quote! {
             let qn = #on_fields_name.split(",").collect::<Vec<&str>>();
-            let c_q =  edgedb_query::queries::conflict::parse_conflict(&self.#f_name, qn);
+            let c_q =  #parse_quote;
             query.push_str(c_q.as_str());
         }
     }
 
+    pub fn query_statement_quote(&self, table_name: String) -> proc_macro2::TokenStream {
+        self.conflict_quote(quote!(self), table_name)
+    }
+
+    pub fn bulk_statement_quote(&self, table_name: String) -> proc_macro2::TokenStream {
+        let conflict_quote = self.conflict_quote(quote!(item), table_name);
+
+        if self.tag.else_select {
+            conflict_quote
+        } else {
+            quote! {
+                if let Some(item) = item {
+                    #conflict_quote
+                }
+            }
+        }
+    }
+
 }
 
 impl TryFrom<(&Field, Vec<String>)> for UnlessConflictElseStatement {
@@ -6,16 +6,19 @@ extern crate proc_macro;
 use proc_macro::TokenStream;
 use syn::parse_macro_input;
 use crate::insert_query::InsertQuery;
-use crate::{meta_data::{TableInfo, QueryMetaData, }, queries::Query};
+use crate::{meta_data::QueryMetaData, queries::Query};
 use crate::delete_query::DeleteQuery;
 use crate::edgedb_enum::EdgedbEnum;
 use crate::edgedb_filters::EdgedbFilters;
 use crate::edgedb_sets::EdgedbSets;
+use crate::edgedb_tuple::EdgedbTuple;
+use crate::edgedb_scalar::EdgedbScalar;
 use crate::file_query::FileQuery;
-use crate::meta_data::{SrcFile, SrcValue};
+use crate::meta_data::{QueryResultMeta, ScalarMeta, SrcFile, SrcValue, UpdateMetaData, UpsertMetaData};
 use crate::query_result::QueryResult;
 use crate::select_query::SelectQuery;
 use crate::update_query::UpdateQuery;
+use crate::upsert_query::UpsertQuery;
 
 mod constants;
 mod utils;
@@ -23,6 +26,7 @@ mod queries;
 mod tags;
 mod statements;
 mod insert_query;
+mod upsert_query;
 mod select_query;
 mod update_query;
 mod delete_query;
@@ -33,6 +37,8 @@ mod query_result;
 mod edgedb_enum;
 mod edgedb_filters;
 mod edgedb_sets;
+mod edgedb_tuple;
+mod edgedb_scalar;
 
 /// Create an insert edgeDB query
 ///
@@ -130,6 +136,50 @@ pub fn insert_query(attr: TokenStream, item: TokenStream) -> TokenStream {
         .unwrap_or_else(|e| e.to_compile_error().into())
 }
 
+/// Create an upsert edgeDB query
+///
+/// The insert statement is followed by an `unless conflict on` statement whose else branch updates
+/// the fields marked `#[overwrite]` (or all the non key fields when none is marked).
+///
+/// ## Usage
+///
+/// ```rust
+///     use edgedb_query::{ToEdgeQuery, EdgeQuery};
+///     use edgedb_query_derive::upsert_query;
+///
+///     #[upsert_query(module = "users", table = "User", on = "email")]
+///     pub struct UpsertUser {
+///         pub email: String,
+///         #[overwrite]
+///         pub name: String,
+///         pub created_at: String,
+///     }
+///
+///     fn main() {
+///         let query: EdgeQuery = UpsertUser {
+///             email: "joe@mail.com".to_string(),
+///             name: "Joe".to_string(),
+///             created_at: "today".to_string(),
+///         }.to_edge_query();
+///
+///         assert_eq!(
+///             query.query,
+///             "insert users::User {email := (select <str>$email), name := (select <str>$name), created_at := (select <str>$created_at), } unless conflict on .email else ( update users::User set { name := (select <str>$name), } ) "
+///         );
+///     }
+/// ```
+#[proc_macro_attribute]
+pub fn upsert_query(attr: TokenStream, item: TokenStream) -> TokenStream {
+
+    let meta = parse_macro_input!(attr as UpsertMetaData);
+
+    let mut query = parse_macro_input!(item as UpsertQuery);
+
+    query.with_meta(meta)
+        .and_then(|q| q.to_token_stream())
+        .unwrap_or_else(|e| e.to_compile_error().into())
+}
+
 /// Create a select edgeDB query
 ///
 /// ## Usage
@@ -185,7 +235,7 @@ pub fn insert_query(attr: TokenStream, item: TokenStream) -> TokenStream {
 #[proc_macro_attribute]
 pub fn select_query(attr: TokenStream, item: TokenStream) -> TokenStream {
 
-    let meta = parse_macro_input!(attr as QueryMetaData);
+    let meta = parse_macro_input!(attr with QueryMetaData::parse_with_optional_table);
 
     parse_macro_input!(item as SelectQuery)
         .with_meta(meta)
@@ -234,7 +284,7 @@ pub fn select_query(attr: TokenStream, item: TokenStream) -> TokenStream {
 #[proc_macro_attribute]
 pub fn update_query(attr: TokenStream, item: TokenStream) -> TokenStream {
 
-    let meta = parse_macro_input!(attr as QueryMetaData);
+    let meta = parse_macro_input!(attr as UpdateMetaData);
 
     parse_macro_input!(item as UpdateQuery)
         .with_meta(meta)
@@ -274,7 +324,7 @@ pub fn update_query(attr: TokenStream, item: TokenStream) -> TokenStream {
 #[proc_macro_attribute]
 pub fn delete_query(attr: TokenStream, item: TokenStream) -> TokenStream {
 
-    let meta = parse_macro_input!(attr as TableInfo);
+    let meta = parse_macro_input!(attr as QueryMetaData);
 
     parse_macro_input!(item as DeleteQuery)
         .with_meta(meta)
@@ -427,10 +477,39 @@ pub fn query(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///         pub friend: Friend,
 ///     }
 /// ```
+///
+/// A result can reuse the shape of another result, either by flattening it into a field
+/// or by extending it (the base result is then accessible through `Deref`)
+///
+/// ```rust
+///     use edgedb_query_derive::query_result;
+///
+///     #[query_result]
+///     pub struct BaseFields {
+///         pub id: uuid::Uuid,
+///         pub created_at: String,
+///     }
+///
+///     #[query_result]
+///     pub struct Post {
+///         #[flatten]
+///         pub base: BaseFields,
+///         pub title: String,
+///     }
+///
+///     #[query_result(extends = "BaseFields")]
+///     pub struct Comment {
+///         pub content: String,
+///     }
+/// ```
 #[proc_macro_attribute]
-pub fn query_result(_: TokenStream, item: TokenStream) -> TokenStream {
+pub fn query_result(attr: TokenStream, item: TokenStream) -> TokenStream {
+
+    let meta = parse_macro_input!(attr as QueryResultMeta);
+
     parse_macro_input!(item as QueryResult)
-        .to_token_stream()
+        .with_meta(meta)
+        .and_then(|r| r.to_token_stream())
         .unwrap_or_else(|e| e.to_compile_error().into())
 }
 
@@ -456,6 +535,58 @@ pub fn edgedb_enum(_: TokenStream, item: TokenStream) -> TokenStream {
         .unwrap_or_else(|e| e.to_compile_error().into())
 }
 
+/// Represents an edgeDB named tuple type
+///
+/// The tuple scalar is built from the fields names and types (e.g. __<tuple<x: float64, y: float64>>__)
+///
+/// ## Usage
+///
+/// ```rust
+///     use edgedb_query::ToEdgeScalar;
+///     use edgedb_query_derive::edgedb_tuple;
+///
+///     #[edgedb_tuple]
+///     pub struct Point {
+///         pub x: f64,
+///         pub y: f64,
+///     }
+///
+///     assert_eq!(Point::scalar(), "<tuple<x: float64, y: float64>>");
+/// ```
+#[proc_macro_attribute]
+pub fn edgedb_tuple(_: TokenStream, item: TokenStream) -> TokenStream {
+    parse_macro_input!(item as EdgedbTuple)
+        .to_token_stream()
+        .unwrap_or_else(|e| e.to_compile_error().into())
+}
+
+/// Represents an edgeDB custom scalar type
+///
+/// The attribute applies to a newtype struct wrapping the scalar's base type,
+/// the value is encoded as the wrapped type and cast to the custom scalar (e.g. __<default::Email>__)
+///
+/// ## Usage
+///
+/// ```rust
+///     use edgedb_query::ToEdgeScalar;
+///     use edgedb_query_derive::edgedb_scalar;
+///
+///     #[edgedb_scalar(name = "default::Email")]
+///     pub struct Email(pub String);
+///
+///     assert_eq!(Email::scalar(), "<default::Email>");
+/// ```
+#[proc_macro_attribute]
+pub fn edgedb_scalar(attr: TokenStream, item: TokenStream) -> TokenStream {
+
+    let meta = parse_macro_input!(attr as ScalarMeta);
+
+    parse_macro_input!(item as EdgedbScalar)
+        .with_meta(meta)
+        .and_then(|s| s.to_token_stream())
+        .unwrap_or_else(|e| e.to_compile_error().into())
+}
+
 /// Represents a list of edgeDB query filters
 ///
 /// ## Usage
@@ -3,9 +3,10 @@ use std::io::{ErrorKind, Read};
 
 use proc_macro2::Span;
 use quote::{quote, ToTokens};
-use syn::{Ident, parse::{Parse, ParseStream}, Token};
+use syn::{Ident, MetaNameValue, parse::{Parse, ParseStream}, Token, Type};
+use syn::punctuated::Punctuated;
 
-use crate::constants::{BASIC_RESULT, DEFAULT_MODULE, EXPECT_LIT, EXPECT_META, EXPECT_NON_EMPTY_LIT, EXPECT_SRC, EXPECT_TABLE, MODULE, RESULT, SRC, VALUE, TABLE, UNSUPPORTED_ATTRIBUTE, EXPECT_VALUE};
+use crate::constants::{BASIC_RESULT, DEFAULT_MODULE, EXPECT_LIT, EXPECT_META, EXPECT_NON_EMPTY_LIT, EXPECT_SRC, EXPECT_TABLE, MODULE, RESULT, SRC, VALUE, TABLE, UNSUPPORTED_ATTRIBUTE, EXPECT_VALUE, EXTENDS, INVALID_RESULT_META, FREE_OBJECT, EXPECT_LIT_BOOL, ON, EXPECT_UPSERT_ON, VERSION, NAME, INVALID_SCALAR_META, EXPECT_SCALAR_NAME};
 
 macro_rules! add_meta {
     ($param_name: ident, $param_value: ident, $builder: ident, $with_result: ident, $with_src: ident, $with_value: ident) => {
@@ -114,7 +115,15 @@ pub struct TableInfo {
 
 impl TableInfo {
     pub fn table_name(&self) -> String {
-        format!("{}::{}", self.module, self.table)
+        if self.table.is_empty() {
+            String::default()
+        } else {
+            format!("{}::{}", self.module, self.table)
+        }
+    }
+
+    pub fn has_table(&self) -> bool {
+        !self.table.is_empty()
     }
 }
 
@@ -130,6 +139,7 @@ impl Parse for TableInfo {
 pub struct TableInfoBuilder {
     pub module: Option<String>,
     pub table: Option<String>,
+    pub table_optional: bool,
 }
 
 impl Builder for TableInfoBuilder {
@@ -149,6 +159,11 @@ impl Builder for TableInfoBuilder {
                 module: self.module.clone().unwrap_or(DEFAULT_MODULE.to_owned()),
                 table,
             })
+        } else if self.table_optional {
+            Ok(TableInfo {
+                module: self.module.clone().unwrap_or(DEFAULT_MODULE.to_owned()),
+                table: String::default(),
+            })
         } else {
             Err(syn::Error::new_spanned(
                 TABLE.to_token_stream(),
@@ -174,12 +189,12 @@ impl QueryMetaData {
     }
 
     pub fn result_quote(&self) -> proc_macro2::TokenStream {
-        if let Some(result) = &self.result {
-            let tty = Ident::new(result.as_str(), Span::call_site());
+        if self.result.is_some() {
+            let tty = self.result();
             quote! {
                 use edgedb_query::ToEdgeShape;
 
-                let shape = #tty::shape();
+                let shape = <#tty as edgedb_query::ToEdgeShape>::shape();
                 query.push_str(shape.as_str());
             }
         } else {
@@ -187,16 +202,31 @@ impl QueryMetaData {
         }
     }
 
-    pub fn result(&self) -> Ident {
-        self.result.clone()
-            .or(Some(BASIC_RESULT.to_string()))
-            .map(|r| Ident::new(r.as_str(), Span::call_site()))
-            .unwrap()
+    pub fn result(&self) -> Type {
+        let result = self.result.clone().unwrap_or(BASIC_RESULT.to_string());
+
+        syn::parse_str::<Type>(result.as_str())
+            .expect("Result type is validated when meta data are built")
+    }
+
+    pub fn validate_result(&self) -> syn::Result<()> {
+        if let Some(result) = &self.result {
+            syn::parse_str::<Type>(result.as_str())
+                .map_err(|_| syn::Error::new(Span::call_site(), format!("Invalid result type `{result}`")))?;
+        }
+        Ok(())
     }
 
     pub fn has_result(&self) -> bool {
         self.result.is_some()
     }
+
+    /// Parse a query meta data whose table can be omitted (free object select)
+    pub fn parse_with_optional_table(input: ParseStream) -> syn::Result<Self> {
+        let mut builder = QueryMetaDataBuilder::default();
+        builder.meta_builder.table_optional = true;
+        builder.parse(input, true, false, false)
+    }
 }
 
 impl Parse for QueryMetaData {
@@ -226,15 +256,186 @@ impl Builder for QueryMetaDataBuilder {
     }
 
     fn build(&self) -> syn::Result<Self::T> {
-        Ok(QueryMetaData {
+        let meta = QueryMetaData {
             meta: self.meta_builder.build()?,
             result: self.result.clone(),
-        })
+        };
+
+        meta.validate_result()?;
+
+        Ok(meta)
     }
 }
 
 // endregion QueryMetaDataBuilder
 
+// region UpsertMetaData
+
+#[derive(Debug, Clone)]
+pub struct UpsertMetaData {
+    pub query: QueryMetaData,
+    pub on: Vec<String>,
+}
+
+impl Parse for UpsertMetaData {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut builder = QueryMetaDataBuilder::default();
+
+        let mut on: Vec<String> = vec![];
+
+        let args = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        for arg in args {
+            let name = arg.path.get_ident().cloned()
+                .ok_or_else(|| syn::Error::new_spanned(&arg.path, UNSUPPORTED_ATTRIBUTE))?;
+
+            let value = match &arg.lit {
+                syn::Lit::Str(s) if s.value().is_empty() => return Err(syn::Error::new_spanned(s, EXPECT_NON_EMPTY_LIT)),
+                syn::Lit::Str(s) => s.value(),
+                _ => return Err(syn::Error::new_spanned(&arg.lit, EXPECT_LIT))
+            };
+
+            if name == ON {
+                on = value.split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+            } else {
+                builder.arg(DataType::try_from((name, true, false, false))?, value);
+            }
+        }
+
+        if on.is_empty() {
+            return Err(syn::Error::new(Span::call_site(), EXPECT_UPSERT_ON));
+        }
+
+        Ok(Self {
+            query: builder.build()?,
+            on,
+        })
+    }
+}
+
+// endregion UpsertMetaData
+
+// region UpdateMetaData
+
+#[derive(Debug, Clone)]
+pub struct UpdateMetaData {
+    pub query: QueryMetaData,
+    pub version: Option<String>,
+}
+
+impl Parse for UpdateMetaData {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut builder = QueryMetaDataBuilder::default();
+
+        let mut version: Option<String> = None;
+
+        let args = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        for arg in args {
+            let name = arg.path.get_ident().cloned()
+                .ok_or_else(|| syn::Error::new_spanned(&arg.path, UNSUPPORTED_ATTRIBUTE))?;
+
+            let value = match &arg.lit {
+                syn::Lit::Str(s) if s.value().is_empty() => return Err(syn::Error::new_spanned(s, EXPECT_NON_EMPTY_LIT)),
+                syn::Lit::Str(s) => s.value(),
+                _ => return Err(syn::Error::new_spanned(&arg.lit, EXPECT_LIT))
+            };
+
+            if name == VERSION {
+                version = Some(value.trim().to_string());
+            } else {
+                builder.arg(DataType::try_from((name, true, false, false))?, value);
+            }
+        }
+
+        Ok(Self {
+            query: builder.build()?,
+            version,
+        })
+    }
+}
+
+// endregion UpdateMetaData
+
+// region QueryResultMeta
+
+#[derive(Debug, Clone, Default)]
+pub struct QueryResultMeta {
+    pub extends: Option<String>,
+    pub free_object: bool,
+}
+
+impl Parse for QueryResultMeta {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut meta = QueryResultMeta::default();
+
+        let args = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        for arg in args {
+            let name = arg.path.get_ident()
+                .map(|i| i.to_string())
+                .unwrap_or_default();
+
+            match (name.as_str(), &arg.lit) {
+                (EXTENDS, syn::Lit::Str(s)) => {
+                    if s.value().is_empty() {
+                        return Err(syn::Error::new_spanned(s, EXPECT_NON_EMPTY_LIT));
+                    }
+                    meta.extends = Some(s.value())
+                }
+                (EXTENDS, _) => return Err(syn::Error::new_spanned(arg.lit, EXPECT_LIT)),
+                (FREE_OBJECT, syn::Lit::Bool(b)) => meta.free_object = b.value(),
+                (FREE_OBJECT, _) => return Err(syn::Error::new_spanned(arg.lit, EXPECT_LIT_BOOL)),
+                _ => return Err(syn::Error::new_spanned(arg, INVALID_RESULT_META))
+            }
+        }
+
+        Ok(meta)
+    }
+}
+
+// endregion QueryResultMeta
+
+// region ScalarMeta
+
+#[derive(Debug, Clone)]
+pub struct ScalarMeta {
+    pub name: String,
+}
+
+impl Parse for ScalarMeta {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut name = None;
+
+        let args = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        for arg in args {
+            let arg_name = arg.path.get_ident()
+                .map(|i| i.to_string())
+                .unwrap_or_default();
+
+            match (arg_name.as_str(), &arg.lit) {
+                (NAME, syn::Lit::Str(s)) => {
+                    if s.value().is_empty() {
+                        return Err(syn::Error::new_spanned(s, EXPECT_NON_EMPTY_LIT));
+                    }
+                    name = Some(s.value())
+                }
+                (NAME, _) => return Err(syn::Error::new_spanned(arg.lit, EXPECT_LIT)),
+                _ => return Err(syn::Error::new_spanned(arg, INVALID_SCALAR_META))
+            }
+        }
+
+        name.map(|name| ScalarMeta { name })
+            .ok_or_else(|| syn::Error::new(input.span(), EXPECT_SCALAR_NAME))
+    }
+}
+
+// endregion ScalarMeta
+
 // region SrcQuery
 
 pub trait SrcQuery {
@@ -2,21 +2,25 @@ use proc_macro::{TokenStream};
 use std::convert::TryFrom;
 use proc_macro2::Span;
 use quote::quote;
-use syn::{Field, Ident, ItemStruct};
+use syn::{Field, Ident, ItemStruct, Type};
 use syn::parse::{Parse, ParseStream};
-use crate::constants::{BACKLINK, EXPECTED_ID_FIELD, FIELD, ID, INVALID_RESULT_FIELD_TAG, LIMIT_1, SCALAR_TYPE, VEC};
+use crate::constants::{BACKLINK, BASE, BOX, EXPECTED_ID_FIELD, EXTENDS_RESERVED_BASE, FIELD, FLATTEN, FLATTEN_ONLY_ALONE, ID, INVALID_RESULT_FIELD_TAG, LIMIT_1, LINK, LINK_ONLY_SELF_REFERENCE, OPTION, SCALAR_TYPE, VEC};
+use crate::meta_data::QueryResultMeta;
 use crate::queries::QueryField;
 use crate::tags::backlink_field_tag::{BackLinkFieldTag, BackLinkFieldTagBuilder};
 use crate::tags::{build_tags_from_field, Tagged};
+use crate::tags::link_tag::{LinkTag, LinkTagBuilder};
 use crate::tags::result_field_tag::{ResultFieldTag, ResultFieldTagBuilder};
-use crate::tags::TagBuilders::{BackLinkFieldBuilder, ResultFieldBuilder};
+use crate::tags::TagBuilders::{BackLinkFieldBuilder, LinkBuilder, ResultFieldBuilder};
 use crate::utils::attributes_utils::has_attribute;
-use crate::utils::derive_utils::format_scalar;
-use crate::utils::type_utils::is_type_name;
+use crate::utils::derive_utils::scalar_quote;
+use crate::utils::type_utils::{get_wrapped_type, is_type_name};
 
 pub struct QueryResult {
     pub ident: Ident,
     pub fields: Vec<ResultField>,
+    pub extends: Option<Type>,
+    pub free_object: bool,
 }
 
 
@@ -25,18 +29,76 @@ impl QueryResult {
         Self {
             ident,
             fields: vec![],
+            extends: None,
+            free_object: false,
         }
     }
 
+    pub fn with_meta(&mut self, meta: QueryResultMeta) -> syn::Result<&mut Self> {
+        self.free_object = meta.free_object;
+
+        if let Some(extends) = meta.extends {
+            let ty = syn::parse_str::<Type>(extends.as_str())?;
+
+            if let Some(f) = self.fields.iter().find(|f| f.field.ident == BASE) {
+                return Err(syn::Error::new_spanned(&f.field.ident, EXTENDS_RESERVED_BASE));
+            }
+
+            self.fields.insert(0, ResultField {
+                field: QueryField {
+                    ident: Ident::new(BASE, Span::call_site()),
+                    ty: ty.clone(),
+                },
+                tag: ResultTags::Flatten,
+            });
+
+            self.extends = Some(ty);
+        }
+
+        Ok(self)
+    }
+
     pub fn to_token_stream(&self) -> syn::Result<TokenStream> {
         let struct_name = self.ident.clone();
 
         let fields = self.fields.iter();
 
-        let id_field = fields.clone().find(|f| f.field.ident == ID)
-            .ok_or_else(|| syn::Error::new(Span::call_site(), EXPECTED_ID_FIELD))?;
+        let has_flatten = fields.clone().any(|f| matches!(f.tag, ResultTags::Flatten));
+
+        let has_link = fields.clone().any(|f| matches!(f.tag, ResultTags::Link(_)));
+
+        for f in fields.clone() {
+            if let ResultTags::Link(_) = f.tag {
+                f.check_self_reference(&struct_name)?;
+            }
+        }
+
+        // link fields are omitted from the deepest levels of the shape, the shallowest ones first
+        let mut links = fields.clone()
+            .filter_map(|f| match f.tag {
+                ResultTags::Link(ref tag) => Some((f.field.ident.clone(), tag.depth)),
+                _ => None
+            })
+            .collect::<Vec<(Ident, usize)>>();
+
+        links.sort_by_key(|(_, depth)| *depth);
 
-        let id_ty = id_field.field.ty.clone();
+        let link_rank = |f: &ResultField| links.iter().position(|(ident, _)| *ident == f.field.ident);
+
+        let id_check = match fields.clone().find(|f| f.field.ident == ID) {
+            Some(id_field) => {
+                let id_ty = id_field.field.ty.clone();
+                quote! {
+                    const _: () = {
+                        use std::marker::PhantomData;
+                        struct Id(PhantomData<uuid::Uuid>);
+                        let _ = Id(PhantomData::<#id_ty>);
+                    };
+                }
+            }
+            None if has_flatten || self.free_object => quote!(),
+            None => return Err(syn::Error::new(Span::call_site(), EXPECTED_ID_FIELD)),
+        };
 
         let fields_quote = fields.clone()
             .map(|f| f.field.struct_field_quote());
@@ -44,39 +106,182 @@ impl QueryResult {
         let field_shapes = fields.clone()
             .map(|f| f.shape_quote());
 
-        let add_field = fields.map(|f|{
-            let f_name =f.field.ident.clone().to_string();
+        let add_field = fields.clone().map(|f| f.returning_field_quote());
+
+        let count_fields = fields.clone().map(|f| f.count_quote());
+
+        let decode_fields = fields.clone().map(|f| f.decode_quote());
+
+        let check_fields = fields.clone().map(|f| f.check_descriptor_quote());
+
+        let decode_present_fields = fields.clone().map(|f| f.decode_present_quote(link_rank(f)));
+
+        let check_present_fields = fields.clone().map(|f| f.check_present_descriptor_quote(link_rank(f)));
+
+        let has_id = fields.clone().any(|f| f.field.ident == ID && !matches!(f.tag, ResultTags::Flatten));
+
+        let flatten_types = fields.clone()
+            .filter(|f| matches!(f.tag, ResultTags::Flatten))
+            .map(|f| f.field.ty.clone());
+
+        // a free object has no id, an object whose fields do not include its id receives it as an implicit element
+        let implicit_id = if self.free_object {
+            quote!(false)
+        } else {
+            quote!(!<Self as edgedb_query::models::query_result::QueryableFields>::selects_id())
+        };
+
+        let field_names = fields.map(|f| f.field.ident.clone());
+
+        let (derive_quote, queryable_quote) = if has_link {
+            let field_names = field_names.clone();
+            (
+                quote!(#[derive(Debug, Clone, Default)]),
+                quote! {
+                    impl edgedb_protocol::queryable::Queryable for #struct_name {
+                        fn decode(decoder: &edgedb_protocol::queryable::Decoder, buf: &[u8]) -> Result<Self, edgedb_protocol::errors::DecodeError> {
+                            let fields_count = <Self as edgedb_query::models::query_result::QueryableFields>::fields_count();
+                            let (mut elements, present) = edgedb_query::models::query_result::open_object(decoder, buf, #implicit_id)?;
+                            let elements = &mut elements;
+                            let omitted = fields_count.saturating_sub(present);
+                            #(#decode_present_fields)*
+                            Ok(Self {
+                                #(#field_names,)*
+                            })
+                        }
+
+                        fn check_descriptor(ctx: &edgedb_protocol::queryable::DescriptorContext, type_pos: edgedb_protocol::descriptors::TypePos) -> Result<(), edgedb_protocol::queryable::DescriptorMismatch> {
+                            let (elements, mut idx) = edgedb_query::models::query_result::object_elements(ctx, type_pos, #implicit_id)?;
+                            let idx = &mut idx;
+                            let omitted = <Self as edgedb_query::models::query_result::QueryableFields>::fields_count().saturating_sub(elements.len() - *idx);
+                            #(#check_present_fields)*
+                            if elements.len() != *idx {
+                                return Err(ctx.field_number(elements.len(), *idx));
+                            }
+                            Ok(())
+                        }
+                    }
+                }
+            )
+        } else if has_flatten || self.free_object {
+            // a free object has no implicit id, its elements are decoded without the edgedb_derive checks
+            (
+                quote!(#[derive(Debug, Clone, Default)]),
+                quote! {
+                    impl edgedb_protocol::queryable::Queryable for #struct_name {
+                        fn decode(decoder: &edgedb_protocol::queryable::Decoder, buf: &[u8]) -> Result<Self, edgedb_protocol::errors::DecodeError> {
+                            edgedb_query::models::query_result::decode_object::<Self>(decoder, buf, #implicit_id)
+                        }
+
+                        fn check_descriptor(ctx: &edgedb_protocol::queryable::DescriptorContext, type_pos: edgedb_protocol::descriptors::TypePos) -> Result<(), edgedb_protocol::queryable::DescriptorMismatch> {
+                            edgedb_query::models::query_result::check_object_descriptor::<Self>(ctx, type_pos, #implicit_id)
+                        }
+                    }
+                }
+            )
+        } else {
+            (quote!(#[derive(Debug, Clone, Default, edgedb_derive::Queryable)]), quote!())
+        };
+
+        let deref_quote = if let Some(base_ty) = self.extends.clone() {
+            let base = Ident::new(BASE, Span::call_site());
             quote! {
-                fields.push(#f_name);
+                impl std::ops::Deref for #struct_name {
+                    type Target = #base_ty;
+
+                    fn deref(&self) -> &Self::Target {
+                        &self.#base
+                    }
+                }
+
+                impl std::ops::DerefMut for #struct_name {
+                    fn deref_mut(&mut self) -> &mut Self::Target {
+                        &mut self.#base
+                    }
+                }
+            }
+        } else {
+            quote!()
+        };
+
+        let shape_quote = if has_link {
+            quote! {
+                impl #struct_name {
+                    #[doc(hidden)]
+                    pub fn shape_at_level(level: usize) -> String {
+
+                        use edgedb_query::ToEdgeScalar;
+
+                        let mut query = "{".to_string();
+                        #(#field_shapes)*
+                        query.pop();
+                        query.push_str("}");
+                        query
+                    }
+                }
+
+                impl edgedb_query::ToEdgeShape for #struct_name {
+                    fn shape() -> String {
+                        Self::shape_at_level(0)
+                    }
+                }
             }
-        });
+        } else {
+            quote! {
+                impl edgedb_query::ToEdgeShape for #struct_name {
+                    fn shape() -> String {
+
+                        use edgedb_query::ToEdgeScalar;
+
+                        let mut query = "{".to_string();
+                        #(#field_shapes)*
+                        query.pop();
+                        query.push_str("}");
+                        query
+                    }
+                }
+            }
+        };
 
         let tokens = quote! {
 
-            const _: () = {
-                use std::marker::PhantomData;
-                struct Id(PhantomData<uuid::Uuid>);
-                let _ = Id(PhantomData::<#id_ty>);
-            };
+            #id_check
 
-            #[derive(Debug, Clone, Default, edgedb_derive::Queryable)]
+            #derive_quote
             pub struct #struct_name {
                 #(#fields_quote)*
             }
 
-            impl edgedb_query::ToEdgeShape for #struct_name {
-                fn shape() -> String {
+            #queryable_quote
+
+            #deref_quote
 
-                    use edgedb_query::ToEdgeScalar;
+            impl edgedb_query::models::query_result::QueryableFields for #struct_name {
+                fn fields_count() -> usize {
+                    let mut count = 0;
+                    #(#count_fields)*
+                    count
+                }
+
+                fn selects_id() -> bool {
+                    #has_id #(|| <#flatten_types as edgedb_query::models::query_result::QueryableFields>::selects_id())*
+                }
+
+                fn decode_fields(decoder: &edgedb_protocol::queryable::Decoder, elements: &mut edgedb_protocol::serialization::decode::DecodeTupleLike) -> Result<Self, edgedb_protocol::errors::DecodeError> {
+                    #(#decode_fields)*
+                    Ok(Self {
+                        #(#field_names,)*
+                    })
+                }
 
-                    let mut query = "{".to_string();
-                    #(#field_shapes)*
-                    query.pop();
-                    query.push_str("}");
-                    query
+                fn check_fields_descriptor(ctx: &edgedb_protocol::queryable::DescriptorContext, elements: &[edgedb_protocol::descriptors::ShapeElement], idx: &mut usize) -> Result<(), edgedb_protocol::queryable::DescriptorMismatch> {
+                    #(#check_fields)*
+                    Ok(())
                 }
             }
 
+            #shape_quote
+
             impl edgedb_query::ToEdgeScalar for #struct_name {
                 fn scalar() -> String {
                     String::default()
@@ -118,6 +323,8 @@ pub enum ResultTags {
     NoTag,
     FieldTag(ResultFieldTag),
     BackLink(BackLinkFieldTag),
+    Flatten,
+    Link(LinkTag),
 }
 // endregion ResultTags
 
@@ -129,6 +336,131 @@ pub struct ResultField {
 
 impl ResultField {
 
+    fn is_boxed_link(&self) -> bool {
+        matches!(self.tag, ResultTags::Link(_)) && is_type_name(&self.field.ty, OPTION)
+    }
+
+    fn check_self_reference(&self, struct_name: &Ident) -> syn::Result<()> {
+        let ty = &self.field.ty;
+
+        let inner = if is_type_name(ty, VEC) {
+            Some(get_wrapped_type(ty, VEC))
+        } else if is_type_name(ty, OPTION) {
+            let boxed = get_wrapped_type(ty, OPTION);
+            if is_type_name(&boxed, BOX) {
+                Some(get_wrapped_type(&boxed, BOX))
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        match inner {
+            Some(inner) if is_type_name(&inner, struct_name.to_string().as_str()) || is_type_name(&inner, "Self") => Ok(()),
+            _ => Err(syn::Error::new_spanned(ty, LINK_ONLY_SELF_REFERENCE))
+        }
+    }
+
+    fn returning_field_quote(&self) -> proc_macro2::TokenStream {
+        let tty = self.field.ty.clone();
+
+        if let ResultTags::Flatten = self.tag {
+            quote! {
+                fields.extend(<#tty as edgedb_query::EdgeResult>::returning_fields());
+            }
+        } else {
+            let f_name = self.field.ident.clone().to_string();
+            quote! {
+                fields.push(#f_name);
+            }
+        }
+    }
+
+    fn count_quote(&self) -> proc_macro2::TokenStream {
+        let tty = self.field.ty.clone();
+
+        if let ResultTags::Flatten = self.tag {
+            quote! {
+                count += <#tty as edgedb_query::models::query_result::QueryableFields>::fields_count();
+            }
+        } else {
+            quote! {
+                count += 1;
+            }
+        }
+    }
+
+    fn decode_quote(&self) -> proc_macro2::TokenStream {
+        let f_ident = self.field.ident.clone();
+        let tty = self.field.ty.clone();
+
+        if let ResultTags::Flatten = self.tag {
+            quote! {
+                let #f_ident = <#tty as edgedb_query::models::query_result::QueryableFields>::decode_fields(decoder, elements)?;
+            }
+        } else if self.is_boxed_link() {
+            quote! {
+                let #f_ident = <Option<Self> as edgedb_protocol::queryable::Queryable>::decode_optional(decoder, elements.read()?)?.map(Box::new);
+            }
+        } else {
+            quote! {
+                let #f_ident = edgedb_protocol::queryable::Queryable::decode_optional(decoder, elements.read()?)?;
+            }
+        }
+    }
+
+    fn decode_present_quote(&self, link_rank: Option<usize>) -> proc_macro2::TokenStream {
+        let f_ident = self.field.ident.clone();
+        let decode = self.decode_quote();
+
+        if let Some(rank) = link_rank {
+            quote! {
+                let #f_ident = if #rank < omitted {
+                    Default::default()
+                } else {
+                    #decode
+                    #f_ident
+                };
+            }
+        } else {
+            decode
+        }
+    }
+
+    fn check_descriptor_quote(&self) -> proc_macro2::TokenStream {
+        let f_name = self.field.ident.clone().to_string();
+        let tty = self.field.ty.clone();
+
+        if let ResultTags::Flatten = self.tag {
+            quote! {
+                <#tty as edgedb_query::models::query_result::QueryableFields>::check_fields_descriptor(ctx, elements, idx)?;
+            }
+        } else if self.is_boxed_link() {
+            quote! {
+                edgedb_query::models::query_result::check_field_descriptor::<Option<Self>>(ctx, elements, idx, #f_name)?;
+            }
+        } else {
+            quote! {
+                edgedb_query::models::query_result::check_field_descriptor::<#tty>(ctx, elements, idx, #f_name)?;
+            }
+        }
+    }
+
+    fn check_present_descriptor_quote(&self, link_rank: Option<usize>) -> proc_macro2::TokenStream {
+        let check = self.check_descriptor_quote();
+
+        if let Some(rank) = link_rank {
+            quote! {
+                if #rank >= omitted {
+                    #check
+                }
+            }
+        } else {
+            check
+        }
+    }
+
     fn shape_quote(&self) -> proc_macro2::TokenStream {
         let f_name = self.field.ident.clone().to_string();
         let tty = self.field.ty.clone();
@@ -136,7 +468,7 @@ impl ResultField {
         match self.tag.clone() {
             ResultTags::NoTag => {
                 quote! {
-                    let shape = #tty::shape();
+                    let shape = <#tty as edgedb_query::ToEdgeShape>::shape();
                     if shape.is_empty() {
                         query.push_str(#f_name);
                         query.push_str(",");
@@ -150,10 +482,9 @@ impl ResultField {
             ResultTags::FieldTag(tag) => {
                 let stmt = tag.build_statement(f_name);
                 let scalar = SCALAR_TYPE.to_string();
-                let format_scalar = format_scalar();
+                let scalar_quote = scalar_quote(&tty);
                 quote! {
-                    let mut scalar: String = #tty::scalar();
-                    #format_scalar;
+                    #scalar_quote
                     let p = #stmt.to_owned().replace(#scalar, scalar.as_str());
                     query.push_str(p.as_str());
                     query.push_str(",");
@@ -178,6 +509,31 @@ impl ResultField {
                     query.push_str(",");
                 }
             }
+
+            ResultTags::Flatten => {
+                quote! {
+                    let shape = <#tty as edgedb_query::ToEdgeShape>::shape();
+                    let shape = shape.trim();
+                    let inner = shape.strip_prefix('{')
+                        .and_then(|s| s.strip_suffix('}'))
+                        .unwrap_or(shape);
+                    if !inner.is_empty() {
+                        query.push_str(inner);
+                        query.push_str(",");
+                    }
+                }
+            }
+
+            ResultTags::Link(tag) => {
+                let depth = tag.depth;
+                quote! {
+                    if level < #depth {
+                        let s = format!("{} : {}", #f_name, Self::shape_at_level(level + 1));
+                        query.push_str(s.as_str());
+                        query.push_str(",");
+                    }
+                }
+            }
         }
 
     }
@@ -192,6 +548,26 @@ impl TryFrom<&Field> for ResultField {
                 field: QueryField::try_from((field, vec![]))?,
                 tag: ResultTags::NoTag,
             })
+        } else if has_attribute(field, FLATTEN) {
+            if field.attrs.len() > 1 {
+                return Err(syn::Error::new_spanned(field, FLATTEN_ONLY_ALONE));
+            }
+
+            Ok(Self {
+                field: QueryField::try_from((field, vec![FLATTEN]))?,
+                tag: ResultTags::Flatten,
+            })
+        } else if has_attribute(field, LINK) {
+            let mut builders = LinkBuilder(LinkTagBuilder::default());
+
+            build_tags_from_field(&Tagged::StructField(field.clone()), vec![&mut builders])?;
+
+            let tag_builder: LinkTagBuilder = builders.into();
+
+            Ok(Self {
+                field: QueryField::try_from((field, vec![LINK]))?,
+                tag: ResultTags::Link(tag_builder.build(field)?),
+            })
         } else if has_attribute(field, FIELD) {
             let mut builders = ResultFieldBuilder(ResultFieldTagBuilder::default());
 
@@ -1,8 +1,9 @@
 use std::convert::TryFrom;
 
 use edgedb_query::QueryType;
+use proc_macro::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{Field, Ident, ItemStruct};
+use syn::{Field, Ident, ItemStruct, Type};
 use syn::parse::{Parse, ParseStream};
 
 use crate::builders::impl_builder::{FieldCat, ImplBuilderField, QueryImplBuilder};
@@ -20,6 +21,7 @@ pub struct SelectQuery {
     pub meta: Option<QueryMetaData>,
     pub filter_statement: FilterStatement,
     pub options: Option<SelectOptions>,
+    pub shape: Option<SelectShape>,
 }
 
 impl SelectQuery {
@@ -29,6 +31,7 @@ impl SelectQuery {
             meta: None,
             filter_statement: FilterStatement::NoFilter,
             options: None,
+            shape: None,
         }
     }
 
@@ -37,6 +40,56 @@ impl SelectQuery {
         set_table_name(&mut self.filter_statement, meta.table_name());
         self
     }
+
+    fn shape_quote(&self, meta: &QueryMetaData) -> proc_macro2::TokenStream {
+        if let Some(shape) = &self.shape {
+            shape.statement_quote(&meta.result())
+        } else {
+            meta.result_quote()
+        }
+    }
+
+    /// Build the paged query implementation of a select query with options :
+    /// the filtered set is bound once and shared by the __total__ and __items__ elements
+    fn paged_impl_quote(&self, meta: &QueryMetaData) -> proc_macro2::TokenStream {
+        let Some(options) = &self.options else {
+            return quote!();
+        };
+
+        let struct_name = &self.ident;
+
+        let table_name = meta.table_name();
+
+        let shape_stmt = self.shape_quote(meta);
+
+        let filter_stmts = self.filter_statement.edgeql_statements(table_name.clone(), false);
+
+        let options_stmt = options.statement_quote(String::default(), &meta.result());
+
+        quote! {
+            impl edgedb_query::queries::paged::PagedQuery for #struct_name {
+                fn paged_edgeql(&self) -> String {
+                    use edgedb_query::ToEdgeScalar;
+                    use edgedb_query::queries::filter::Filter;
+                    use edgedb_query::EdgeResult;
+
+                    let mut query = String::new();
+                    #(#filter_stmts)*
+                    let filters = query;
+
+                    let mut query = String::new();
+                    #shape_stmt
+                    let shape = query;
+
+                    let mut query = String::new();
+                    #options_stmt
+                    let options = query;
+
+                    edgedb_query::queries::paged::paged_statement(#table_name, filters.as_str(), shape.as_str(), options.as_str())
+                }
+            }
+        }
+    }
 }
 
 impl Query for SelectQuery {
@@ -49,11 +102,32 @@ impl Query for SelectQuery {
 
         let table_name = meta.table_name();
 
+        if !meta.meta.has_table() {
+            if !meta.has_result() {
+                return Err(syn::Error::new_spanned(&self.ident, FREE_OBJECT_SELECT_EXPECTS_RESULT));
+            }
+
+            if self.options.is_some() || !matches!(self.filter_statement, FilterStatement::NoFilter) {
+                return Err(syn::Error::new_spanned(&self.ident, FREE_OBJECT_SELECT_NO_FILTER));
+            }
+        }
+
         let mut fields =  self.filter_statement.to_impl_builder_field();
 
         let mut edgeql_statements = vec![];
 
-        edgeql_statements.push(meta.result_quote());
+        if let Some(shape) = self.shape.clone() {
+            if !meta.has_result() {
+                return Err(syn::Error::new_spanned(&self.ident, SHAPE_EXPECTS_RESULT));
+            }
+
+            fields.push(ImplBuilderField {
+                field: shape.field.clone(),
+                field_cat: FieldCat::Ignore,
+            });
+        }
+
+        edgeql_statements.push(self.shape_quote(&meta));
 
         match self.filter_statement {
             FilterStatement::NoFilter => {}
@@ -89,6 +163,20 @@ impl Query for SelectQuery {
             has_result: false
         })
     }
+
+    fn to_token_stream(&self) -> syn::Result<TokenStream> {
+        let meta = try_get_meta(&self.ident, || self.meta.clone())?;
+
+        let query_quote = self.to_impl_builder()?.build();
+
+        let paged_quote = self.paged_impl_quote(&meta);
+
+        Ok(quote! {
+            #query_quote
+
+            #paged_quote
+        }.into())
+    }
 }
 
 impl Parse for SelectQuery {
@@ -119,8 +207,24 @@ impl Parse for SelectQuery {
 
         // endregion handle options field if exists
 
+        // region handle shape field if exists
+        let shape_fields: Vec<&Field> = field_iter.clone()
+            .filter(|f| has_attribute(f, SHAPE))
+            .collect::<Vec<&Field>>();
+
+        match shape_fields.len() {
+            0 => {}
+            1 => {
+                query.shape = Some(SelectShape::try_from(shape_fields[0])?);
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(shape_fields[1].to_token_stream(), ONLY_ONE_SHAPE_TAG_EXPECTED));
+            }
+        }
+        // endregion handle shape field if exists
+
         // region add filters statements
-        query.filter_statement = filters_from_fields(field_iter, vec![OPTIONS], FilterRequiredQuery::Select,INVALID_SELECT_TAG)?;
+        query.filter_statement = filters_from_fields(field_iter, vec![OPTIONS, SHAPE], FilterRequiredQuery::Select,INVALID_SELECT_TAG)?;
         // endregion add filters statements
 
         query.check_duplicate_parameter_labels()?;
@@ -150,19 +254,34 @@ impl SelectOptions {
         }
     }
 
-    pub fn statement_quote(&self, table_name: String, result_type_name: &Ident) -> proc_macro2::TokenStream {
+    pub fn statement_quote(&self, table_name: String, result_type: &Type) -> proc_macro2::TokenStream {
+        self.options_quote(quote! {
+            edgedb_query::queries::select::parse_options(v, #table_name.to_owned(), <#result_type as edgedb_query::EdgeResult>::returning_fields())
+        })
+    }
+
+    /// Build the options statement without checking the 'order by' value against the result fields
+    pub fn unchecked_statement_quote(&self, table_name: String) -> proc_macro2::TokenStream {
+        self.options_quote(quote! {
+            edgedb_query::queries::select::parse_unchecked_options(v, #table_name.to_owned())
+        })
+    }
+
+    /// __parse_quote__ : the options parsing expression, the options being bound to __v__
+    fn options_quote(&self, parse_quote: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
         let opt_f_ident = &self.field.ident;
 
         if is_type_name(&self.field.ty, OPTION) {
             quote! {
-                if let Some(v) = self.#opt_f_ident {
-                    let c_q = edgedb_query::queries::select::parse_options(&v, #table_name.to_owned(), #result_type_name::returning_fields());
+                if let Some(v) = &self.#opt_f_ident {
+                    let c_q = #parse_quote;
                     query.push_str(c_q.as_str());
                 }
             }
         } else {
             quote! {
-                let c_q =  edgedb_query::queries::select::parse_options(&self.#opt_f_ident, #table_name.to_owned(), #result_type_name::returning_fields());
+                let v = &self.#opt_f_ident;
+                let c_q = #parse_quote;
                 query.push_str(c_q.as_str());
             }
         }
@@ -180,3 +299,47 @@ impl TryFrom<&Field> for SelectOptions {
 }
 
 // endregion SelectOptionsStatement
+
+
+// region: SelectShapeStatement
+
+#[derive(Debug, Clone)]
+pub struct SelectShape {
+    pub field: QueryField,
+}
+
+impl SelectShape {
+    pub fn statement_quote(&self, result_type: &Type) -> proc_macro2::TokenStream {
+        let shape_f_ident = &self.field.ident;
+
+        if is_type_name(&self.field.ty, OPTION) {
+            quote! {
+                let shape = match &self.#shape_f_ident {
+                    Some(s) => {
+                        let s: &edgedb_query::DynamicShape<#result_type> = s;
+                        s.shape()
+                    }
+                    None => <#result_type as edgedb_query::ToEdgeShape>::shape(),
+                };
+                query.push_str(shape.as_str());
+            }
+        } else {
+            quote! {
+                let shape: &edgedb_query::DynamicShape<#result_type> = &self.#shape_f_ident;
+                query.push_str(shape.shape().as_str());
+            }
+        }
+    }
+}
+
+impl TryFrom<&Field> for SelectShape {
+    type Error = syn::Error;
+
+    fn try_from(field: &Field) -> Result<Self, Self::Error> {
+        Ok(Self {
+            field: QueryField::try_from((field, vec![SHAPE]))?,
+        })
+    }
+}
+
+// endregion SelectShapeStatement
@@ -1,7 +1,7 @@
 use std::convert::TryFrom;
 
-use quote::{quote, ToTokens};
-use syn::{Field, Ident};
+use quote::{format_ident, quote, ToTokens};
+use syn::{Field, Ident, parse_quote};
 use syn::punctuated::Iter;
 
 use crate::constants::*;
@@ -10,10 +10,10 @@ use crate::queries::{check_duplicate_parameter_labels, QueryField};
 use crate::statements::nested_query::{NestedQueryField, NestedQueryParentType};
 use crate::tags::{build_tags_from_field, Tagged};
 use crate::tags::field_tag::{FieldTag, FieldTagBuilder};
-use crate::tags::filter_tag::{FilterTagBuilder, FilterTags};
+use crate::tags::filter_tag::{FilterTag, FilterTagBuilder, FilterTags, OtherFilterTags, SelectFilterOperator};
 use crate::tags::TagBuilders::{FieldBuilder, FilterBuilder};
 use crate::utils::attributes_utils::{has_any_attribute, has_attribute, has_none_attribute};
-use crate::utils::derive_utils::{nested_element_shape, nested_element_value};
+use crate::utils::derive_utils::{nested_element_shape, nested_element_value, resolved_statement_quote};
 use crate::utils::type_utils::{get_scalar, get_type_name};
 
 #[derive(Debug, Clone)]
@@ -84,12 +84,40 @@ impl QueryFilter {
             } else {
                 format!("{SELECT}{SPACE}{ty}${param}")
             };
-            Ok(format!("{conjunctive}{SPACE}{wrapped_field_name}{SPACE}{symbol}{SPACE}({param_stmt})"))
+            if filter_operator.is_function() {
+                Ok(format!("{conjunctive}{SPACE}{symbol}({wrapped_field_name}, ({param_stmt}))"))
+            } else {
+                Ok(format!("{conjunctive}{SPACE}{wrapped_field_name}{SPACE}{symbol}{SPACE}({param_stmt})"))
+            }
+        }
+    }
+
+    /// Build the version check filter of a versioned update query (e.g. __users::User.version = (select <int64>$expected_version)__)
+    pub fn version_check(version: impl Into<String>, first: bool) -> Self {
+        let tag = FilterTag {
+            operator: SelectFilterOperator::Is,
+            wrapper_fn: None,
+        };
+
+        Self {
+            field: QueryField {
+                ident: format_ident!("{}", EXPECTED_VERSION),
+                ty: parse_quote!(i64),
+            },
+            field_tag: FieldTag {
+                column_name: version.into(),
+                parameter_label: EXPECTED_VERSION.to_string(),
+                scalar_type: "<int64>".to_string(),
+                link: None,
+            },
+            filter_tag: if first { FilterTags::First(tag) } else { FilterTags::Other(OtherFilterTags::And(tag)) },
         }
     }
 
     pub fn push_to_query_quote(&self, filter_stmt: String,  from_filters: bool) -> proc_macro2::TokenStream {
 
+        let filter_stmt = resolved_statement_quote(filter_stmt, &self.field.ty);
+
         if from_filters {
             let __tablename__ = __TABLENAME__;
             quote! {
@@ -97,7 +125,7 @@ impl QueryFilter {
             }
         } else {
             quote! {
-                 query.push_str(#filter_stmt);
+                 query.push_str(#filter_stmt.as_str());
             }
         }
     }
@@ -223,6 +251,41 @@ impl FilterStatement {
         }
     }
 
+    /// Build the filter statements of a versioned update query : the filters are wrapped and followed by the version check
+    pub fn versioned_edgeql_statements(&self, table_name: impl Into<String>, version: impl Into<String>) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+        let table_name = table_name.into();
+        let version = version.into();
+
+        match self {
+            FilterStatement::NoFilter => {
+                let version_check = QueryFilter::version_check(version, true);
+                let stmt = version_check.push_to_query_quote(version_check.build_statement(table_name, false)?, false);
+
+                Ok(vec![quote! {
+                    query.push_str(#FILTER);
+                    #stmt
+                }])
+            }
+            FilterStatement::ManyFilter(filters) => {
+                let filter_q = format!("{FILTER} (");
+                let query_filters = filters.iter()
+                    .map(|filter| Ok(filter.push_to_query_quote(filter.build_statement(table_name.clone())?, false)))
+                    .collect::<syn::Result<Vec<_>>>()?;
+
+                let version_check = QueryFilter::version_check(version, false);
+                let stmt = version_check.push_to_query_quote(version_check.build_statement(table_name, false)?, false);
+
+                Ok(vec![quote! {
+                    query.push_str(#filter_q);
+                    #(#query_filters)*
+                    query.push_str(" )");
+                    #stmt
+                }])
+            }
+            FilterStatement::OneFilters(filters) => Err(syn::Error::new_spanned(&filters.field.ident, VERSION_WITH_FILTERS))
+        }
+    }
+
     pub fn struct_field_quote(&self) -> proc_macro2::TokenStream {
         match self {
             FilterStatement::NoFilter => quote!(),
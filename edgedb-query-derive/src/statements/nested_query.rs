@@ -3,10 +3,11 @@ use quote::quote;
 
 use syn::Field;
 
-use crate::constants::{EDGEQL, NESTED_QUERY};
+use crate::constants::{EDGEQL, NESTED_QUERY, OPTION, VEC};
 use crate::queries::QueryField;
 use crate::statements::filters::QueryFilter;
 use crate::statements::set::UpdateSet;
+use crate::utils::type_utils::{get_type, is_type_name};
 
 // region NestedQueryField
 #[derive(Debug, Clone)]
@@ -73,6 +74,16 @@ impl NestedQueryField {
         }
     }
 
+    /// Whether the field is a list of nested insert queries (Vec<T>)
+    pub fn is_many(&self) -> bool {
+        self.set.is_none() && self.filter.is_none() && is_type_name(&self.field.ty, VEC)
+    }
+
+    /// Whether the field is an optional nested insert query (Option<T>), whose parameters are prefixed with the field name
+    pub fn is_optional(&self) -> bool {
+        self.set.is_none() && self.filter.is_none() && is_type_name(&self.field.ty, OPTION)
+    }
+
     pub fn add_stmt_quote(&self, to_query: bool) -> syn::Result<proc_macro2::TokenStream> {
         let f_name = self.field.ident.clone();
 
@@ -88,9 +99,21 @@ impl NestedQueryField {
             quote!(set_stmt.push_str(p.as_str());)
         };
 
-        Ok(quote! {
+        if self.is_many() {
+            let ty = get_type(&self.field.ty);
+            let param = f_name.to_string();
+
+            return Ok(quote! {
+                let nested_edgeql = edgedb_query::queries::bulk::nested_bulk_insert_statement::<#ty>(#param);
+
+                let p = #field_statement.to_owned()
+                    .replace(#edge_ql, nested_edgeql.as_str());
+
+                #add_quote
+            });
+        }
 
-            let mut nested_edgeql = self.#f_name.to_edgeql();
+        let add_nested_quote = quote! {
             let nested_table_name = nested_edgeql.table_name.clone();
 
             if #parent_table_name == nested_table_name.as_str() {
@@ -101,7 +124,23 @@ impl NestedQueryField {
                 .replace(#edge_ql, nested_edgeql.to_string().as_str());
 
             #add_quote
-        })
+        };
+
+        if self.is_optional() {
+            let prefix = f_name.to_string();
+
+            Ok(quote! {
+                if let Some(nested) = &self.#f_name {
+                    let mut nested_edgeql = nested.to_edgeql().prefixed_params(#prefix);
+                    #add_nested_quote
+                }
+            })
+        } else {
+            Ok(quote! {
+                let mut nested_edgeql = self.#f_name.to_edgeql();
+                #add_nested_quote
+            })
+        }
     }
 
 }
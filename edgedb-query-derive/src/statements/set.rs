@@ -4,16 +4,18 @@ use proc_macro2::Ident;
 use quote::{quote, ToTokens};
 use syn::Field;
 use syn::punctuated::Iter;
-use crate::constants::{EDGEQL, EITHER_ONE_SETS_OR_SET_TAG_EXPECTED, FIELD, INVALID_UPDATE_TAG, NESTED_QUERY, ONLY_ONE_SETS_TAG_EXPECTED, SELECT, SET, SETS};
+use crate::constants::{ASSIGN_SIGN, EDGEQL, EITHER_ONE_SETS_OR_SET_TAG_EXPECTED, EXPR, FIELD, INVALID_UPDATE_TAG, LINK_PROPERTY_ON_REMOVED_LINK, LINK_PROPERTY_TARGET_EXPECTED, NESTED_QUERY, OPTION, ONLY_ONE_SETS_TAG_EXPECTED, SELECT, SET, SETS, UNIT_TYPE};
 use crate::builders::impl_builder::{FieldCat, ImplBuilderField};
 
 use crate::queries::{check_duplicate_parameter_labels, QueryField};
 use crate::statements::nested_query::{NestedQueryField, NestedQueryParentType};
+use crate::tags::expr_tag::{ExprTag, ExprTagBuilder};
 use crate::tags::field_tag::{FieldTag, FieldTagBuilder};
-use crate::tags::set_tag::{SetOption, SetTag, SetTagBuilder};
+use crate::tags::set_tag::{NoneOption, SetOption, SetTag, SetTagBuilder};
 use crate::tags::{build_tags_from_field, TagBuilders, Tagged};
 use crate::utils::attributes_utils::{has_any_attribute, has_attribute, has_none_attribute};
-use crate::utils::derive_utils::{nested_element_shape, nested_element_value};
+use crate::utils::derive_utils::{link_target_quote, nested_element_shape, nested_element_value, resolved_statement_quote};
+use crate::utils::type_utils::{get_type, is_link_type, is_type_name};
 
 
 // region SetStatement
@@ -26,6 +28,7 @@ pub enum SetStatement {
 impl SetStatement {
     pub fn param_field(&self) -> Option<(Ident, String)> {
         match self {
+            Self::SimpleField(f) if !f.has_param() => None,
             Self::SimpleField(f) => Some((f.field.ident.clone(), f.field_tag.parameter_label.clone())),
             Self::NestedQuery(f) => f.set.clone().map(|s| (s.field.ident.clone(), s.field_tag.parameter_label))
         }
@@ -47,6 +50,7 @@ impl SetStatement {
 
     pub fn shape_quote(&self) -> proc_macro2::TokenStream {
         match self {
+            SetStatement::SimpleField(s) if !s.has_param() => quote!(),
             SetStatement::SimpleField(s) => s.field.field_shape_quote(s.field_tag.parameter_label.clone()),
             SetStatement::NestedQuery(nq) => nested_element_shape(nq.field.ident.clone())
         }
@@ -54,6 +58,7 @@ impl SetStatement {
 
     pub fn value_quote(&self) -> proc_macro2::TokenStream {
         match self {
+            SetStatement::SimpleField(s) if !s.has_param() => quote!(),
             SetStatement::SimpleField(s) => s.field.field_value_quote(),
             SetStatement::NestedQuery(nq) => nested_element_value(nq.field.ident.clone())
         }
@@ -71,7 +76,7 @@ impl TryFrom<&Field> for SetStatement {
     fn try_from(field: &Field) -> Result<Self, Self::Error> {
         if has_attribute(field, NESTED_QUERY) {
             Ok(SetStatement::NestedQuery(NestedQueryField::try_from((field, NestedQueryParentType::Set))?))
-        } else if has_any_attribute(field, vec![SET, FIELD]) || field.attrs.is_empty() {
+        } else if has_any_attribute(field, vec![SET, FIELD, EXPR]) || field.attrs.is_empty() {
             Ok(SetStatement::SimpleField(UpdateSet::try_from(field)?))
         } else {
             Err(syn::Error::new_spanned(field.to_token_stream(), INVALID_UPDATE_TAG))
@@ -85,16 +90,25 @@ pub struct UpdateSet {
     pub field: QueryField,
     pub field_tag: FieldTag,
     pub set_tag: SetTag,
+    pub expr: Option<ExprTag>,
+    pub properties: Vec<(Ident, bool, proc_macro2::TokenStream)>,
 }
 
 impl UpdateSet {
+    /// returns false when the field is of type (), i.e. an expression without parameter
+    pub fn has_param(&self) -> bool {
+        !is_type_name(&self.field.ty, UNIT_TYPE)
+    }
+
     pub fn build_statement(&self, is_nested: bool) -> String {
         let column_name = self.field_tag.column_name.clone();
         let scalar_type = self.field_tag.scalar_type.clone();
         let param = self.field_tag.parameter_label.clone();
         let assignment = self.set_tag.option.statement();
 
-        let param_stmt = if is_nested {
+        let param_stmt = if let Some(ExprTag(expr)) = &self.expr {
+            expr.clone()
+        } else if is_nested {
             EDGEQL.to_string()
         } else {
             format!("{SELECT} {scalar_type}${param}")
@@ -109,9 +123,55 @@ impl UpdateSet {
     }
 
     pub fn add_set_statement_quote(&self) -> proc_macro2::TokenStream {
-        let stmt = self.build_statement(false);
+        // a link property is assigned within its link statement
+        if self.field_tag.link.is_some() {
+            return quote!();
+        }
+
+        let add_quote = if is_link_type(&self.field.ty) {
+            self.link_set_statement_quote()
+        } else {
+            let stmt = resolved_statement_quote(self.build_statement(false), &self.field.ty);
+            quote! {
+               set_stmt.push_str(#stmt.as_str());
+            }
+        };
+
+        if is_type_name(&self.field.ty, OPTION) {
+            let f_name = self.field.ident.clone();
+
+            let none_quote = match self.set_tag.if_none {
+                NoneOption::Skip => quote!(),
+                NoneOption::Clear => {
+                    let stmt = format!("{} {ASSIGN_SIGN} {{}}, ", self.field_tag.column_name);
+                    quote! {
+                        set_stmt.push_str(#stmt);
+                    }
+                }
+            };
+
+            quote! {
+                if self.#f_name.is_some() {
+                    #add_quote
+                } else {
+                    #none_quote
+                }
+            }
+        } else {
+            add_quote
+        }
+    }
+
+    /// Build the set statement of a typed link reference (Link<T> or Links<T>) :
+    /// the linked objects are selected by their ids
+    fn link_set_statement_quote(&self) -> proc_macro2::TokenStream {
+        let stmt = self.build_statement(true);
+        let edge_ql = EDGEQL.to_string();
+        let link_quote = link_target_quote(&get_type(&self.field.ty), self.field_tag.parameter_label.clone(), self.properties.clone());
+
         quote! {
-           set_stmt.push_str(#stmt);
+            #link_quote
+            set_stmt.push_str(#stmt.replace(#edge_ql, link.as_str()).as_str());
         }
     }
 }
@@ -122,16 +182,22 @@ impl TryFrom<&Field> for UpdateSet {
     fn try_from(field: &Field) -> Result<Self, Self::Error> {
         let mut field_tag_builder = TagBuilders::FieldBuilder(FieldTagBuilder::default());
         let mut set_tag_builder = TagBuilders::SetBuilder(SetTagBuilder::default());
+        let mut expr_tag_builder = TagBuilders::ExprBuilder(ExprTagBuilder::default());
 
-        build_tags_from_field(&Tagged::StructField(field.clone()), vec![&mut field_tag_builder, &mut set_tag_builder])?;
+        build_tags_from_field(&Tagged::StructField(field.clone()), vec![&mut field_tag_builder, &mut set_tag_builder, &mut expr_tag_builder])?;
 
         let field_tag_builder: FieldTagBuilder = field_tag_builder.into();
         let set_tag_builder: SetTagBuilder = set_tag_builder.into();
+        let expr_tag_builder: ExprTagBuilder = expr_tag_builder.into();
+
+        let field_tag = field_tag_builder.build(field)?;
 
         Ok(Self {
-            field: QueryField::try_from((field, vec![FIELD, SET, NESTED_QUERY]))?,
-            field_tag: field_tag_builder.build(field)?,
+            field: QueryField::try_from((field, vec![FIELD, SET, NESTED_QUERY, EXPR]))?,
+            expr: expr_tag_builder.build(field, &field_tag.parameter_label, field_tag.link.is_some())?,
+            field_tag,
             set_tag: set_tag_builder.build(field)?,
+            properties: vec![],
         })
     }
 }
@@ -202,6 +268,10 @@ impl UpdateSetStatement {
             UpdateSetStatement::ManySet(filters) => {
                 filters.iter().map(|stmt| {
                     match stmt {
+                        SetStatement::SimpleField(f) if !f.has_param() => ImplBuilderField {
+                            field: f.field.clone(),
+                            field_cat: FieldCat::Ignore,
+                        },
                         SetStatement::SimpleField(f) => ImplBuilderField {
                             field: f.field.clone(),
                             field_cat: FieldCat::Simple(f.field_tag.parameter_label.clone()),
@@ -234,11 +304,9 @@ impl UpdateSetStatement {
                 });
 
                 quote! {
-                    let mut set_stmt = "set { ".to_string();
+                    let mut set_stmt = String::new();
                     #(#set_stmts)*
-                    set_stmt.pop();
-                    set_stmt.pop();
-                    set_stmt.push_str(" }");
+                    let set_stmt = format!("set {{ {} }}", set_stmt.trim().trim_end_matches(','));
                 }
             }
         }
@@ -319,7 +387,7 @@ pub fn sets_from_fields(field_iter: Iter<Field>, exclude_tags: Vec<&str>, from_s
 
     for field in field_iter {
         if has_none_attribute(field, exclude_tags.clone()) && !has_attribute(field, SETS) {
-            if has_any_attribute(field, vec![SET, NESTED_QUERY, FIELD]) || field.attrs.is_empty() {
+            if has_any_attribute(field, vec![SET, NESTED_QUERY, FIELD, EXPR]) || field.attrs.is_empty() {
                 if let UpdateSetStatement::OneSets(_) = stmt {
                     return Err(syn::Error::new_spanned(field, EITHER_ONE_SETS_OR_SET_TAG_EXPECTED));
                 }
@@ -331,10 +399,46 @@ pub fn sets_from_fields(field_iter: Iter<Field>, exclude_tags: Vec<&str>, from_s
         }
     }
 
+    attach_link_properties(&mut sets)?;
+
     if !sets.is_empty() {
         stmt = UpdateSetStatement::ManySet(sets);
     }
 
     Ok(stmt)
 }
+
+/// Attach each link property field (#[field(link = "...")]) to the Link or Links field it belongs to
+fn attach_link_properties(sets: &mut [SetStatement]) -> syn::Result<()> {
+    let properties = sets.iter()
+        .filter_map(|set| match set {
+            SetStatement::SimpleField(f) => f.field_tag.link.clone().map(|link| (link, f.clone())),
+            SetStatement::NestedQuery(_) => None,
+        })
+        .collect::<Vec<(String, UpdateSet)>>();
+
+    for (link, property) in properties {
+        let target = sets.iter_mut()
+            .find_map(|set| match set {
+                SetStatement::SimpleField(f) if is_link_type(&f.field.ty) && f.field.ident == link.as_str() => Some(f),
+                _ => None,
+            });
+
+        let Some(target) = target else {
+            return Err(syn::Error::new_spanned(&property.field.ident, format!("{LINK_PROPERTY_TARGET_EXPECTED} : `{link}`")));
+        };
+
+        if let SetOption::Remove = target.set_tag.option {
+            return Err(syn::Error::new_spanned(&property.field.ident, LINK_PROPERTY_ON_REMOVED_LINK));
+        }
+
+        target.properties.push((
+            property.field.ident.clone(),
+            is_type_name(&property.field.ty, OPTION),
+            resolved_statement_quote(property.field_tag.link_property_statement(), &property.field.ty),
+        ));
+    }
+
+    Ok(())
+}
 // endregion UpdateSetStatement
\ No newline at end of file
@@ -0,0 +1,119 @@
+use std::ops::Range;
+use syn::{Field, LitStr};
+use crate::constants::{DOLLAR, EXPECT_NON_EMPTY_LIT, EXPR, EXPR_ON_LINK, EXPR_PARAM_EXPECTED, UNIT_TYPE};
+use crate::tags::{TagBuilders, ValueTagBuilder};
+use crate::utils::type_utils::{is_link_type, is_type_name};
+
+/// ExprTag holds the raw EdgeQL expression assigned to a field
+#[derive(Debug, Clone)]
+pub struct ExprTag(pub(crate) String);
+
+impl ExprTag {
+    /// returns the expression whose references to the parameter __param__ are replaced by __value__
+    pub fn replace_param(&self, param: &str, value: &str) -> String {
+        let mut expr = self.0.clone();
+
+        for range in param_ranges(&self.0, param).into_iter().rev() {
+            expr.replace_range(range, value);
+        }
+
+        expr
+    }
+}
+
+/// returns the ranges of the references to the parameter __param__ within __expr__,
+/// string literals and comments being skipped
+fn param_ranges(expr: &str, param: &str) -> Vec<Range<usize>> {
+    let bytes = expr.as_bytes();
+    let is_ident = |b: &u8| b.is_ascii_alphanumeric() || *b == b'_';
+    let ident_end = |from: usize| from + bytes[from..].iter().take_while(|b| is_ident(b)).count();
+
+    let mut ranges = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'#' => {
+                i = expr[i..].find('\n').map_or(bytes.len(), |n| i + n);
+            }
+            quote @ (b'\'' | b'"') => {
+                let raw = i > 0 && bytes[i - 1] == b'r' && (i < 2 || !is_ident(&bytes[i - 2]));
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    i += if bytes[i] == b'\\' && !raw { 2 } else { 1 };
+                }
+                i += 1;
+            }
+            b'$' => {
+                let end = ident_end(i + 1);
+
+                if bytes.get(end) == Some(&b'$') {
+                    // $$...$$ or $tag$...$tag$ raw string
+                    let delimiter = &expr[i..=end];
+                    i = expr[end + 1..].find(delimiter).map_or(bytes.len(), |n| end + 1 + n + delimiter.len());
+                } else {
+                    if &expr[i + 1..end] == param {
+                        ranges.push(i..end);
+                    }
+                    i = end.max(i + 1);
+                }
+            }
+            _ => i += 1,
+        }
+    }
+
+    ranges
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ExprTagBuilder {
+    pub value: Option<String>
+}
+
+impl ExprTagBuilder {
+
+    /// __parameter_label__ : the field's parameter, expected within the expression unless the field type is ()
+    ///
+    /// __is_link_property__ : whether the field is a link property, which cannot be an expression
+    pub fn build(self, field: &Field, parameter_label: &str, is_link_property: bool) -> syn::Result<Option<ExprTag>> {
+        let Some(expr) = self.value else {
+            return Ok(None);
+        };
+
+        if is_link_type(&field.ty) || is_link_property {
+            return Err(syn::Error::new_spanned(field, EXPR_ON_LINK));
+        }
+
+        if !is_type_name(&field.ty, UNIT_TYPE) && param_ranges(&expr, parameter_label).is_empty() {
+            return Err(syn::Error::new_spanned(field, format!("{EXPR_PARAM_EXPECTED} `{DOLLAR}{parameter_label}`")));
+        }
+
+        Ok(Some(ExprTag(expr)))
+    }
+}
+
+impl From<TagBuilders> for ExprTagBuilder {
+    fn from(builders: TagBuilders) -> Self {
+        if let TagBuilders::ExprBuilder(builder) = builders {
+            builder
+        } else {
+            unreachable!()
+        }
+    }
+}
+
+impl ValueTagBuilder for ExprTagBuilder {
+    fn tag_names(&self) -> Vec<&str> {
+        vec![EXPR]
+    }
+
+    fn arg(&mut self, lit_str: &LitStr) -> syn::Result<()> {
+        let value = lit_str.value();
+        if value.trim().is_empty() {
+            Err(syn::Error::new_spanned(lit_str, EXPECT_NON_EMPTY_LIT))
+        } else {
+            self.value = Some(value.trim().to_owned());
+            Ok(())
+        }
+    }
+}
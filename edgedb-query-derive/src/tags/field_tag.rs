@@ -1,7 +1,7 @@
 use std::convert::TryFrom;
 use syn::{Field, MetaNameValue};
 use syn::Lit::{Bool, Str};
-use crate::constants::{COLUMN_NAME, EXPECT_NON_EMPTY_LIT, FIELD, INVALID_FIELD_TAG, PARAM, SCALAR, LINK_PROPERTY, EXPECT_LIT_BOOL, EXPECT_LIT_STR, INF_SIGN, SUP_SIGN, NESTED_QUERY, AT_LEAST_ONE_FIELD_ATTRIBUTE_EXPECTED};
+use crate::constants::{COLUMN_NAME, EXPECT_NON_EMPTY_LIT, FIELD, INVALID_FIELD_TAG, PARAM, SCALAR, LINK_PROPERTY, EXPECT_LIT_BOOL, EXPECT_LIT_STR, INF_SIGN, SUP_SIGN, NESTED_QUERY, AT_LEAST_ONE_FIELD_ATTRIBUTE_EXPECTED, LINK, SELECT};
 use crate::tags::{NamedValueTagBuilder, TagBuilders};
 use crate::tags::TagBuilders::FieldBuilder;
 use crate::tags::utils::{get_column_name, validate_link_property};
@@ -14,8 +14,15 @@ pub struct FieldTag {
     pub column_name: String,
     pub parameter_label: String,
     pub scalar_type: String,
+    pub link: Option<String>,
 }
 
+impl FieldTag {
+    /// returns the link property assignment statement (e.g. __@since := (select <datetime>$since)__)
+    pub fn link_property_statement(&self) -> String {
+        format!("{} := ({SELECT} {}${})", self.column_name, self.scalar_type, self.parameter_label)
+    }
+}
 
 // endregion FieldTag
 
@@ -24,7 +31,8 @@ pub enum FieldTagOptions {
     ColumnName(String),
     ParameterLabel(String),
     ScalarType(String),
-    LinkProperty(bool)
+    LinkProperty(bool),
+    Link(String)
 }
 
 impl TryFrom<&MetaNameValue> for FieldTagOptions {
@@ -44,6 +52,7 @@ impl TryFrom<&MetaNameValue> for FieldTagOptions {
                     COLUMN_NAME => Ok(FieldTagOptions::ColumnName(value.value())),
                     PARAM => Ok(FieldTagOptions::ParameterLabel(value.value())),
                     SCALAR => Ok(FieldTagOptions::ScalarType(value.value())),
+                    LINK => Ok(FieldTagOptions::Link(value.value())),
                     LINK_PROPERTY => Err(syn::Error::new_spanned(meta_value, EXPECT_LIT_BOOL)),
                     _ => Err(syn::Error::new_spanned(meta_value, INVALID_FIELD_TAG))
                 }
@@ -52,7 +61,7 @@ impl TryFrom<&MetaNameValue> for FieldTagOptions {
             Bool(value) => {
                 match path.get_ident().unwrap().to_string().as_str() {
                     LINK_PROPERTY => Ok(FieldTagOptions::LinkProperty(value.value())),
-                    COLUMN_NAME | PARAM | SCALAR => Err(syn::Error::new_spanned(meta_value, EXPECT_LIT_STR)),
+                    COLUMN_NAME | PARAM | SCALAR | LINK => Err(syn::Error::new_spanned(meta_value, EXPECT_LIT_STR)),
                     _ => Err(syn::Error::new_spanned(meta_value, INVALID_FIELD_TAG))
                 }
             }
@@ -70,7 +79,8 @@ pub struct FieldTagBuilder {
     pub column_name: Option<String>,
     pub parameter_label: Option<String>,
     pub scalar_type: Option<String>,
-    pub link_property: Option<bool>
+    pub link_property: Option<bool>,
+    pub link: Option<String>,
 }
 
 impl From<TagBuilders> for FieldTagBuilder {
@@ -93,7 +103,8 @@ impl NamedValueTagBuilder for FieldTagBuilder {
             FieldTagOptions::ColumnName(value) => self.column_name = Some(value),
             FieldTagOptions::ParameterLabel(value) => self.parameter_label = Some(value),
             FieldTagOptions::ScalarType(value) => self.scalar_type = Some(value),
-            FieldTagOptions::LinkProperty(value) => self.link_property = Some(value)
+            FieldTagOptions::LinkProperty(value) => self.link_property = Some(value),
+            FieldTagOptions::Link(value) => self.link = Some(value)
         }
 
         Ok(())
@@ -104,11 +115,15 @@ impl FieldTagBuilder {
 
     pub fn build(&self, field: &Field) -> syn::Result<FieldTag> {
 
-        validate_link_property(self.column_name.clone(), self.link_property, field)?;
+        // a property of a link target is always a link property
+        let link_property = if self.link.is_some() { Some(true) } else { self.link_property };
+
+        validate_link_property(self.column_name.clone(), link_property, field)?;
 
         let all_nones = self.column_name.is_none() 
             && self.parameter_label.is_none() 
-            && self.scalar_type.is_none();
+            && self.scalar_type.is_none()
+            && self.link.is_none();
         
         if has_attribute(field, FIELD) && all_nones {
             return Err(syn::Error::new_spanned(field, AT_LEAST_ONE_FIELD_ATTRIBUTE_EXPECTED));
@@ -121,7 +136,8 @@ impl FieldTagBuilder {
                 scalar = format!("{INF_SIGN}{scalar}");
             }
 
-            if !scalar.ends_with(SUP_SIGN) {
+            // nested types like tuple<x: float64> end with a sign which is not the cast closing one
+            if scalar.matches(INF_SIGN).count() > scalar.matches(SUP_SIGN).count() {
                 scalar = format!("{scalar}{SUP_SIGN}");
             }
 
@@ -133,9 +149,10 @@ impl FieldTagBuilder {
         };
 
         Ok(FieldTag {
-            column_name: get_column_name(self.column_name.clone(), self.link_property, field),
+            column_name: get_column_name(self.column_name.clone(), link_property, field),
             parameter_label: self.parameter_label.clone().unwrap_or(field.ident.as_ref().unwrap().to_string()),
             scalar_type,
+            link: self.link.clone(),
         })
     }
 }
@@ -5,7 +5,7 @@ use crate::constants::*;
 use crate::tags::{NamedValueTagBuilder, TagBuilders};
 use crate::tags::TagBuilders::FilterBuilder;
 use crate::utils::attributes_utils::has_attribute;
-use crate::utils::type_utils::is_type_name;
+use crate::utils::type_utils::{is_range_type, is_type_name};
 
 
 // region FilterTags
@@ -175,6 +175,8 @@ pub enum SelectFilterOperator {
     LesserThan,
     GreaterThanOrEqual,
     LesserThanOrEqual,
+    Overlaps,
+    ContainsValue,
 }
 
 impl SelectFilterOperator {
@@ -192,13 +194,20 @@ impl SelectFilterOperator {
             SelectFilterOperator::GreaterThanOrEqual => ">=",
             SelectFilterOperator::LesserThanOrEqual => "<=",
             SelectFilterOperator::Exists => "exists",
-            SelectFilterOperator::NotExists => "not exists"
+            SelectFilterOperator::NotExists => "not exists",
+            SelectFilterOperator::Overlaps => "overlaps",
+            SelectFilterOperator::ContainsValue => "contains",
         }
     }
 
-    pub fn check_exist(self) -> bool {
+    pub fn check_exist(&self) -> bool {
         matches!(self, SelectFilterOperator::Exists | SelectFilterOperator::NotExists)
     }
+
+    /// Check if the operator is rendered as a function call : __op(field, param)__
+    pub fn is_function(&self) -> bool {
+        matches!(self, SelectFilterOperator::Overlaps | SelectFilterOperator::ContainsValue)
+    }
 }
 
 impl TryFrom<(&Type, LitStr, bool)> for SelectFilterOperator {
@@ -285,6 +294,20 @@ impl TryFrom<(&Type, LitStr, bool)> for SelectFilterOperator {
                 check_not_accepted_type(ty)?;
                 Ok(SelectFilterOperator::LesserThanOrEqual)
             }
+            OVERLAPS => {
+                check_not_accepted_type(ty)?;
+                if !is_range_type(ty) && !is_nested {
+                    return Err(syn::Error::new_spanned(ty, format!("{OVERLAPS} {ONLY_TYPE_FOR_OPERATOR} {RANGE_TYPE}")));
+                }
+                Ok(SelectFilterOperator::Overlaps)
+            }
+            CONTAINS_VALUE => {
+                check_not_accepted_type(ty)?;
+                if is_range_type(ty) {
+                    return Err(syn::Error::new_spanned(ty, CONTAINS_VALUE_ON_RANGE));
+                }
+                Ok(SelectFilterOperator::ContainsValue)
+            }
             _ => Err(syn::Error::new_spanned(lit, INVALID_OPERATOR))
         }
     }
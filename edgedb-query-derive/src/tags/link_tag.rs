@@ -0,0 +1,73 @@
+use std::convert::TryFrom;
+use syn::{Field, MetaNameValue};
+use syn::Lit::Int;
+use crate::constants::{DEPTH, EXPECT_LIT_INT, EXPECTED_LINK_DEPTH, INVALID_LINK_TAG, LINK};
+use crate::tags::{NamedValueTagBuilder, TagBuilders};
+use crate::tags::TagBuilders::LinkBuilder;
+
+// region LinkTag
+#[derive(Debug, Clone)]
+pub struct LinkTag {
+    pub depth: usize,
+}
+// endregion LinkTag
+
+// region LinkTagOptions
+pub enum LinkTagOptions {
+    Depth(usize)
+}
+
+impl TryFrom<&MetaNameValue> for LinkTagOptions {
+    type Error = syn::Error;
+
+    fn try_from(meta_value: &MetaNameValue) -> Result<Self, Self::Error> {
+        if let MetaNameValue { ref path, lit: Int(value), .. } = meta_value {
+            match path.get_ident().unwrap().to_string().as_str() {
+                DEPTH => Ok(LinkTagOptions::Depth(value.base10_parse::<usize>()?)),
+                _ => Err(syn::Error::new_spanned(meta_value, INVALID_LINK_TAG))
+            }
+        } else {
+            Err(syn::Error::new_spanned(meta_value, EXPECT_LIT_INT))
+        }
+    }
+}
+// endregion LinkTagOptions
+
+// region LinkTagBuilder
+#[derive(Debug, Clone, Default)]
+pub struct LinkTagBuilder {
+    pub depth: Option<usize>,
+}
+
+impl From<TagBuilders> for LinkTagBuilder {
+    fn from(value: TagBuilders) -> Self {
+        match value {
+            LinkBuilder(builder) => builder,
+            _ => unreachable!()
+        }
+    }
+}
+
+impl NamedValueTagBuilder for LinkTagBuilder {
+    fn tag_names(&self) -> Vec<&str> {
+        vec![LINK]
+    }
+
+    fn arg(&mut self, meta_value: &MetaNameValue) -> syn::Result<()> {
+        match LinkTagOptions::try_from(meta_value)? {
+            LinkTagOptions::Depth(value) => self.depth = Some(value),
+        }
+
+        Ok(())
+    }
+}
+
+impl LinkTagBuilder {
+    pub fn build(self, field: &Field) -> syn::Result<LinkTag> {
+        match self.depth {
+            Some(depth) if depth > 0 => Ok(LinkTag { depth }),
+            _ => Err(syn::Error::new_spanned(field, EXPECTED_LINK_DEPTH))
+        }
+    }
+}
+// endregion LinkTagBuilder
@@ -4,8 +4,10 @@ use syn::Meta::{List, NameValue};
 use syn::NestedMeta::{Lit, Meta};
 use crate::constants::{EXPECT_LIT_OR_NAMED_LIT, EXPECT_LIT_STR, EXPECT_NAMED_LIT, ONLY_ONE_KIND_OF_TAG_EXPECTED, UNLESS_CONFLICT};
 use crate::tags::backlink_field_tag::BackLinkFieldTagBuilder;
+use crate::tags::expr_tag::ExprTagBuilder;
 use crate::tags::field_tag::FieldTagBuilder;
 use crate::tags::filter_tag::FilterTagBuilder;
+use crate::tags::link_tag::LinkTagBuilder;
 use crate::tags::param_tag::ParamTagBuilder;
 use crate::tags::result_field_tag::ResultFieldTagBuilder;
 use crate::tags::set_tag::SetTagBuilder;
@@ -19,6 +21,8 @@ pub mod result_field_tag;
 pub mod backlink_field_tag;
 pub mod value_tag;
 pub mod unless_conflict_tag;
+pub mod link_tag;
+pub mod expr_tag;
 pub mod utils;
 
 
@@ -41,7 +45,9 @@ pub enum TagBuilders {
     ResultFieldBuilder(ResultFieldTagBuilder),
     BackLinkFieldBuilder(BackLinkFieldTagBuilder),
     EnumValueBuilder(value_tag::EnumValueTagBuilder),
-    UnlessConfictBuilder(unless_conflict_tag::UnlessConflictTagBuilder)
+    UnlessConfictBuilder(unless_conflict_tag::UnlessConflictTagBuilder),
+    LinkBuilder(LinkTagBuilder),
+    ExprBuilder(ExprTagBuilder),
 }
 
 
@@ -52,6 +58,7 @@ impl TagBuilders {
                 match self {
                     TagBuilders::ParamBuilder(builder) => builder.arg(lit),
                     TagBuilders::EnumValueBuilder(builder) => builder.arg(lit),
+                    TagBuilders::ExprBuilder(builder) => builder.arg(lit),
                     _ => Err(syn::Error::new_spanned(nested, EXPECT_NAMED_LIT))
                 }
             }
@@ -63,6 +70,7 @@ impl TagBuilders {
                     TagBuilders::ResultFieldBuilder(builder) => builder.arg(meta_value),
                     TagBuilders::BackLinkFieldBuilder(builder) => builder.arg(meta_value),
                     TagBuilders::UnlessConfictBuilder(builder) => builder.arg(meta_value),
+                    TagBuilders::LinkBuilder(builder) => builder.arg(meta_value),
                     _ => Err(syn::Error::new_spanned(nested, EXPECT_LIT_STR))
                 }
             }
@@ -79,7 +87,9 @@ impl TagBuilders {
             TagBuilders::ResultFieldBuilder(builder) => builder.tag_names(),
             TagBuilders::BackLinkFieldBuilder(builder) => builder.tag_names(),
             TagBuilders::EnumValueBuilder(builder) => builder.tag_names(),
-            TagBuilders::UnlessConfictBuilder(builder) => builder.tag_names()
+            TagBuilders::UnlessConfictBuilder(builder) => builder.tag_names(),
+            TagBuilders::LinkBuilder(builder) => builder.tag_names(),
+            TagBuilders::ExprBuilder(builder) => builder.tag_names(),
         }
     }
 }
@@ -1,7 +1,7 @@
 use std::convert::TryFrom;
 use syn::{Field, MetaNameValue};
 use syn::Lit::{Bool, Str};
-use crate::constants::{COLUMN_NAME, DEFAULT_VALUE, EXPECT_NON_EMPTY_LIT, FIELD, INVALID_FIELD_TAG, SCALAR_TYPE, WRAPPER_FN, LINK_PROPERTY, INVALID_RESULT_FIELD_TAG, EXPECT_LIT_STR, EXPECT_LIT_BOOL};
+use crate::constants::{COLUMN_NAME, DEFAULT_VALUE, EXPECT_NON_EMPTY_LIT, FIELD, INVALID_FIELD_TAG, SCALAR_TYPE, WRAPPER_FN, LINK_PROPERTY, INVALID_RESULT_FIELD_TAG, EXPECT_LIT_STR, EXPECT_LIT_BOOL, EXPR};
 use crate::tags::{NamedValueTagBuilder, TagBuilders};
 use crate::tags::TagBuilders::ResultFieldBuilder;
 use crate::tags::utils::{get_column_name, validate_link_property};
@@ -13,6 +13,7 @@ pub struct ResultFieldTag {
     pub column_name: String,
     pub wrapper_fn: Option<String>,
     pub default_value: Option<String>,
+    pub expr: Option<String>,
 }
 
 impl ResultFieldTag {
@@ -20,8 +21,8 @@ impl ResultFieldTag {
 
         let column = self.column_name.clone();
 
-        let mut s = match self.wrapper_fn.clone() {
-            None => {
+        let mut s = match (self.wrapper_fn.clone(), self.expr.clone()) {
+            (None, None) => {
                 if column.replace('@', "") != f_name {
                     format!("{f_name} := .{column}")
                 } else {
@@ -29,8 +30,14 @@ impl ResultFieldTag {
                 }
             }
 
-           Some(wrapper_fn) =>
-                format!("{f_name} := (select {SCALAR_TYPE}{wrapper_fn}(.{column}))")
+            (None, Some(expr)) =>
+                format!("{f_name} := ({expr})"),
+
+            (Some(wrapper_fn), None) =>
+                format!("{f_name} := (select {SCALAR_TYPE}{wrapper_fn}(.{column}))"),
+
+            (Some(wrapper_fn), Some(expr)) =>
+                format!("{f_name} := (select {SCALAR_TYPE}{wrapper_fn}({expr}))"),
         };
 
         if let Some(v) = self.default_value.clone() {
@@ -48,6 +55,7 @@ pub enum ResultFieldTagOptions {
     WrapperFn(String),
     DefaultValue(String),
     LinkProperty(bool),
+    Expr(String),
 }
 
 impl TryFrom<&MetaNameValue> for ResultFieldTagOptions {
@@ -67,6 +75,7 @@ impl TryFrom<&MetaNameValue> for ResultFieldTagOptions {
                     COLUMN_NAME => Ok(ResultFieldTagOptions::ColumnName(value.value())),
                     WRAPPER_FN => Ok(ResultFieldTagOptions::WrapperFn(value.value())),
                     DEFAULT_VALUE => Ok(ResultFieldTagOptions::DefaultValue(value.value())),
+                    EXPR => Ok(ResultFieldTagOptions::Expr(value.value())),
                     LINK_PROPERTY => Err(syn::Error::new_spanned(meta_value, EXPECT_LIT_BOOL)),
                     _ => Err(syn::Error::new_spanned(meta_value, INVALID_RESULT_FIELD_TAG))
                 }
@@ -75,7 +84,7 @@ impl TryFrom<&MetaNameValue> for ResultFieldTagOptions {
             Bool(value) => {
                 match path.get_ident().unwrap().to_string().as_str() {
                     LINK_PROPERTY => Ok(ResultFieldTagOptions::LinkProperty(value.value())),
-                    COLUMN_NAME | WRAPPER_FN | DEFAULT_VALUE => Err(syn::Error::new_spanned(meta_value, EXPECT_LIT_STR)),
+                    COLUMN_NAME | WRAPPER_FN | DEFAULT_VALUE | EXPR => Err(syn::Error::new_spanned(meta_value, EXPECT_LIT_STR)),
                     _ => Err(syn::Error::new_spanned(meta_value, INVALID_FIELD_TAG))
                 }
             }
@@ -93,6 +102,7 @@ pub struct ResultFieldTagBuilder {
     pub wrapper_fn: Option<String>,
     pub default_value: Option<String>,
     pub link_property: Option<bool>,
+    pub expr: Option<String>,
 }
 
 impl From<TagBuilders> for ResultFieldTagBuilder {
@@ -116,7 +126,8 @@ impl NamedValueTagBuilder for ResultFieldTagBuilder {
             ResultFieldTagOptions::ColumnName(value) => self.column_name = Some(value),
             ResultFieldTagOptions::WrapperFn(value) => self.wrapper_fn = Some(value.replace(['(', ')'], "")),
             ResultFieldTagOptions::DefaultValue(value) => self.default_value = Some(value),
-            ResultFieldTagOptions::LinkProperty(value) => self.link_property = Some(value)
+            ResultFieldTagOptions::LinkProperty(value) => self.link_property = Some(value),
+            ResultFieldTagOptions::Expr(value) => self.expr = Some(value),
         }
 
         Ok(())
@@ -134,6 +145,7 @@ impl ResultFieldTagBuilder {
             self.wrapper_fn.clone(),
             self.default_value.clone(),
             self.link_property.map(|v| v.to_string()),
+            self.expr.clone(),
         ]
             .iter()
             .all(|o| o.is_none());
@@ -141,7 +153,12 @@ impl ResultFieldTagBuilder {
         if has_attribute(field, FIELD) && all_nones {
             Err(syn::Error::new_spanned(
                 field,
-                "#[field] must have at least column_name, wrapper_fn or link_property attribute",
+                "#[field] must have at least column_name, wrapper_fn, link_property or expr attribute",
+            ))
+        } else if self.expr.is_some() && (self.column_name.is_some() || self.link_property.is_some()) {
+            Err(syn::Error::new_spanned(
+                field,
+                "#[field] expr attribute cannot be combined with column_name or link_property attributes",
             ))
         } else {
 
@@ -149,6 +166,7 @@ impl ResultFieldTagBuilder {
                 column_name: get_column_name(self.column_name.clone(), self.link_property, field),
                 wrapper_fn: self.wrapper_fn.clone(),
                 default_value: self.default_value,
+                expr: self.expr,
             })
         }
     }
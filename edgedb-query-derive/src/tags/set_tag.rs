@@ -6,12 +6,13 @@ use crate::constants::*;
 use crate::tags::{NamedValueTagBuilder, TagBuilders};
 use crate::tags::TagBuilders::SetBuilder;
 use crate::utils::attributes_utils::has_attribute;
-use crate::utils::type_utils::is_type_name;
+use crate::utils::type_utils::{is_links_type, is_type_name};
 
 // region SetTag
 #[derive(Debug, Clone)]
 pub struct SetTag {
     pub option: SetOption,
+    pub if_none: NoneOption,
 }
 
 // endregion SetTag
@@ -19,7 +20,8 @@ pub struct SetTag {
 // region SetTagOptions
 #[derive(Debug, Clone)]
 pub enum SetTagOptions {
-    Option(LitStr)
+    Option(LitStr),
+    IfNone(LitStr)
 }
 
 impl TryFrom<&MetaNameValue> for SetTagOptions {
@@ -34,6 +36,7 @@ impl TryFrom<&MetaNameValue> for SetTagOptions {
 
             match path.get_ident().unwrap().to_string().as_str() {
                 SET_OPTION => Ok(SetTagOptions::Option(value.clone())),
+                IF_NONE => Ok(SetTagOptions::IfNone(value.clone())),
 
                 _ => Err(syn::Error::new_spanned(meta_value, INVALID_SET_TAG_OPTION))
             }
@@ -74,7 +77,7 @@ impl TryFrom<(&Type, LitStr, bool)> for SetOption {
             ASSIGN | ASSIGN_SIGN => Ok(SetOption::Assign),
             CONCAT | CONCAT_SIGN => Ok(SetOption::Concat),
             PUSH | PUSH_SIGN => {
-                if is_type_name(ty, VEC) || is_nested {
+                if is_type_name(ty, VEC) || is_links_type(ty) || is_nested {
                     Ok(SetOption::Push)
                 } else {
                     Err(syn::Error::new_spanned(lit, PUSH_OPTION_ONLY_FOR_VEC))
@@ -82,7 +85,7 @@ impl TryFrom<(&Type, LitStr, bool)> for SetOption {
 
             },
             REMOVE | REMOVE_SIGN => {
-                if is_type_name(ty, VEC) || is_nested {
+                if is_type_name(ty, VEC) || is_links_type(ty) || is_nested {
                     Ok(SetOption::Remove)
                 } else {
                     Err(syn::Error::new_spanned(lit, REMOVE_OPTION_ONLY_FOR_VEC))
@@ -95,24 +98,59 @@ impl TryFrom<(&Type, LitStr, bool)> for SetOption {
 }
 // endregion SetOption
 
+// region NoneOption
+/// What an update does with an optional field set to None
+#[derive(Debug, Clone, PartialEq)]
+pub enum NoneOption {
+    /// the assignment is left out of the set clause
+    Skip,
+    /// the column is assigned an empty set
+    Clear
+}
+
+impl TryFrom<(&Type, LitStr)> for NoneOption {
+    type Error = syn::Error;
+
+    fn try_from((ty, lit): (&Type, LitStr)) -> Result<Self, Self::Error> {
+        if !is_type_name(ty, OPTION) {
+            return Err(syn::Error::new_spanned(lit, IF_NONE_ONLY_FOR_OPTION));
+        }
+
+        match lit.value().to_lowercase().as_str() {
+            SKIP => Ok(NoneOption::Skip),
+            CLEAR => Ok(NoneOption::Clear),
+            _ => Err(syn::Error::new_spanned(lit, INVALID_IF_NONE_OPTION))
+        }
+    }
+}
+// endregion NoneOption
+
 // region SetTagBuilder
 
 #[derive(Debug, Clone, Default)]
 pub struct SetTagBuilder {
-    pub option: Option<SetTagOptions>,
+    pub option: Option<LitStr>,
+    pub if_none: Option<LitStr>,
 }
 
 impl SetTagBuilder {
     pub fn build(self, field: &Field) -> syn::Result<SetTag> {
-        if let Some(SetTagOptions::Option(lit)) = self.option {
-            Ok(SetTag {
-                option: SetOption::try_from((&field.ty, lit, has_attribute(field, NESTED_QUERY)))?
-            })
+        let option = if let Some(lit) = self.option {
+            SetOption::try_from((&field.ty, lit, has_attribute(field, NESTED_QUERY)))?
         } else {
-            Ok(SetTag {
-                option: SetOption::Assign
-            })
-        }
+            SetOption::Assign
+        };
+
+        let if_none = if let Some(lit) = self.if_none {
+            NoneOption::try_from((&field.ty, lit))?
+        } else {
+            NoneOption::Skip
+        };
+
+        Ok(SetTag {
+            option,
+            if_none
+        })
     }
 }
 
@@ -131,8 +169,10 @@ impl NamedValueTagBuilder for SetTagBuilder {
     }
 
     fn arg(&mut self, meta_value: &MetaNameValue) -> syn::Result<()> {
-        let option = SetTagOptions::try_from(meta_value)?;
-        self.option = Some(option);
+        match SetTagOptions::try_from(meta_value)? {
+            SetTagOptions::Option(lit) => self.option = Some(lit),
+            SetTagOptions::IfNone(lit) => self.if_none = Some(lit),
+        }
         Ok(())
     }
 }
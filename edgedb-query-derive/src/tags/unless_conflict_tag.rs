@@ -1,7 +1,7 @@
 use std::convert::TryFrom;
-use syn::{Field, MetaNameValue};
+use syn::{Field, MetaNameValue, Type};
 use syn::Lit::Str;
-use crate::constants::{EXPECT_NON_EMPTY_LIT, INVALID_UNLESS_CONFLICT_TAG, UNLESS_CONFLICT, ON, AT};
+use crate::constants::{EXPECT_NON_EMPTY_LIT, INVALID_UNLESS_CONFLICT_TAG, UNLESS_CONFLICT, ON, AT, ELSE, SELECT, INVALID_UNLESS_CONFLICT_ELSE, UNLESS_CONFLICT_ELSE_TYPE, UNLESS_CONFLICT_ELSE_TYPE_NAME};
 use crate::tags::{NamedValueTagBuilder, TagBuilders};
 use crate::tags::TagBuilders::UnlessConfictBuilder;
 
@@ -9,13 +9,15 @@ use crate::tags::TagBuilders::UnlessConfictBuilder;
 #[derive(Debug, Clone)]
 pub struct UnlessConflictTag {
     pub on: Vec<String>,
+    pub else_select: bool,
 }
 
 // endregion FieldTag
 
 // region FieldTagOptions
 pub enum UnlessConflictTagOptions {
-    On(String)
+    On(String),
+    ElseSelect,
 }
 
 impl TryFrom<&MetaNameValue> for UnlessConflictTagOptions {
@@ -29,6 +31,8 @@ impl TryFrom<&MetaNameValue> for UnlessConflictTagOptions {
 
             match path.get_ident().unwrap().to_string().as_str() {
                 ON=> Ok(UnlessConflictTagOptions::On(value.value())),
+                ELSE if value.value() == SELECT => Ok(UnlessConflictTagOptions::ElseSelect),
+                ELSE => Err(syn::Error::new_spanned(value, INVALID_UNLESS_CONFLICT_ELSE)),
                 _ => Err(syn::Error::new_spanned(meta_value, INVALID_UNLESS_CONFLICT_TAG))
             }
         } else {
@@ -43,6 +47,7 @@ impl TryFrom<&MetaNameValue> for UnlessConflictTagOptions {
 #[derive(Debug, Clone, Default)]
 pub struct UnlessConflictTagBuilder {
     pub on: Option<String>,
+    pub else_select: bool,
 }
 
 impl From<TagBuilders> for UnlessConflictTagBuilder {
@@ -63,6 +68,7 @@ impl NamedValueTagBuilder for UnlessConflictTagBuilder {
         let option = UnlessConflictTagOptions::try_from(meta_value)?;
         match option {
             UnlessConflictTagOptions::On(value) => self.on = Some(value),
+            UnlessConflictTagOptions::ElseSelect => self.else_select = true,
         }
 
         Ok(())
@@ -85,18 +91,32 @@ impl UnlessConflictTagBuilder {
         if !x.is_empty() {
             return Err(syn::Error::new_spanned(field, format!("Following names are not column names : {x:#?}")));
         }
-        
+
+        if self.else_select && is_conflict_else_type(&field.ty) {
+            return Err(syn::Error::new_spanned(&field.ty, UNLESS_CONFLICT_ELSE_TYPE));
+        }
+
         Ok(UnlessConflictTag {
             on: ons.into_iter()
                 .map(|o| {
                     let formatted = format!("{AT}{o}");
                     if columns.contains(&formatted) { formatted } else { o }
                 })
-                .collect()
+                .collect(),
+            else_select: self.else_select,
         })
     }
 }
 
+pub fn is_conflict_else_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(p) => p.path.segments.last()
+            .map(|s| s.ident == UNLESS_CONFLICT_ELSE_TYPE_NAME)
+            .unwrap_or(false),
+        _ => false
+    }
+}
+
 // endregion FieldTagBuilder
 
 
@@ -1,14 +1,14 @@
 use edgedb_query::QueryType;
-use quote::quote;
-use syn:: Ident;
+use quote::{format_ident, quote};
+use syn::Ident;
 use syn::ItemStruct;
 use syn::parse::{Parse, ParseStream};
 
-use crate::builders::impl_builder::QueryImplBuilder;
-use crate::constants::{AND_FILTER, EXPECTED_AT_LEAST_ONE_SET_FIELD, FILTER, FILTERS, INVALID_UPDATE_TAG, OR_FILTER, SET, SETS};
-use crate::meta_data::{QueryMetaData, try_get_meta};
+use crate::builders::impl_builder::{FieldCat, ImplBuilderField, QueryImplBuilder};
+use crate::constants::{AND_FILTER, EXPECTED_AT_LEAST_ONE_SET_FIELD, EXPECTED_VERSION, EXPR, FILTER, FILTERS, INVALID_UPDATE_TAG, OR_FILTER, SET, SETS, VERSION_RESERVED_FIELD};
+use crate::meta_data::{QueryMetaData, try_get_meta, UpdateMetaData};
 use crate::queries::Query;
-use crate::statements::filters::{FilterRequiredQuery, filters_from_fields, FilterStatement, set_table_name};
+use crate::statements::filters::{FilterRequiredQuery, filters_from_fields, FilterStatement, QueryFilter, set_table_name};
 use crate::statements::set::{sets_from_fields, UpdateSetStatement};
 
 pub struct UpdateQuery {
@@ -16,6 +16,7 @@ pub struct UpdateQuery {
     pub meta: Option<QueryMetaData>,
     pub filter_statement: FilterStatement,
     pub set_statement: UpdateSetStatement,
+    pub version: Option<String>,
 }
 
 impl UpdateQuery {
@@ -25,15 +26,56 @@ impl UpdateQuery {
             meta: None,
             filter_statement: FilterStatement::NoFilter,
             set_statement: UpdateSetStatement::None,
+            version: None,
         }
     }
 
-    pub fn with_meta(&mut self, meta: QueryMetaData) -> &mut Self {
+    pub fn with_meta(&mut self, meta: UpdateMetaData) -> &mut Self {
+        let UpdateMetaData { query: meta, version } = meta;
         self.meta = Some(meta.clone());
+        self.version = version;
         self.set_statement.set_parent_table_name(meta.table_name());
         set_table_name(&mut self.filter_statement, meta.table_name());
         self
     }
+
+    /// returns the version check filter, whose field is added to the struct of a versioned update query
+    fn version_check(&self) -> Option<QueryFilter> {
+        self.version.as_ref().map(|version| QueryFilter::version_check(version, false))
+    }
+
+    /// Check that no user field takes the name of the expected version field
+    fn check_version_field(&self) -> syn::Result<()> {
+        if self.version.is_none() {
+            return Ok(());
+        }
+
+        let mut labels = self.filter_statement.get_parameter_labels();
+        labels.extend(self.set_statement.get_parameter_labels());
+
+        match labels.iter().find(|(ident, label)| ident == EXPECTED_VERSION || label == EXPECTED_VERSION) {
+            Some((ident, _)) => Err(syn::Error::new_spanned(ident, VERSION_RESERVED_FIELD)),
+            None => Ok(())
+        }
+    }
+
+    /// Build the VersionedUpdate implementation of a versioned update query
+    fn versioned_impl_quote(&self) -> proc_macro2::TokenStream {
+        if self.version.is_none() {
+            return quote!();
+        }
+
+        let struct_name = &self.ident;
+        let expected_version = format_ident!("{}", EXPECTED_VERSION);
+
+        quote! {
+            impl edgedb_query::queries::version::VersionedUpdate for #struct_name {
+                fn expected_version(&self) -> i64 {
+                    self.#expected_version
+                }
+            }
+        }
+    }
 }
 
 impl Query for UpdateQuery {
@@ -41,6 +83,7 @@ impl Query for UpdateQuery {
         let mut p = self.filter_statement.get_parameter_labels();
         let v = self.set_statement.get_parameter_labels();
         p.extend(v);
+        p.extend(self.version_check().map(|f| (f.field.ident, f.field_tag.parameter_label)));
 
         p
     }
@@ -64,15 +107,40 @@ impl Query for UpdateQuery {
 
         fields.extend(set_fields);
 
-        let mut edgeql_statements = self.filter_statement.edgeql_statements(table_name.clone(), false);
+        self.check_version_field()?;
+
+        let mut edgeql_statements = if let Some(version) = self.version.clone() {
+            self.filter_statement.versioned_edgeql_statements(table_name.clone(), version)?
+        } else {
+            self.filter_statement.edgeql_statements(table_name.clone(), false)
+        };
 
         let add_set = self.set_statement.add_set_statement_quote(Some(table_name.clone()));
 
-        edgeql_statements.push(quote! {
-            query.push_str(" ");
-            #add_set
-            query.push_str(&set_stmt);
-        });
+        if let Some(version) = self.version.clone() {
+            fields.extend(self.version_check().map(|filter| ImplBuilderField {
+                field: filter.field,
+                field_cat: FieldCat::Simple(filter.field_tag.parameter_label),
+            }));
+
+            edgeql_statements.push(quote! {
+                query.push_str(" ");
+                #add_set
+                let set_stmt = edgedb_query::queries::version::version_set(set_stmt.as_str(), #version);
+                query.push_str(&set_stmt);
+            });
+        } else {
+            edgeql_statements.push(quote! {
+                query.push_str(" ");
+                #add_set
+                // an update without assignment is invalid, the filtered objects are only selected
+                if edgedb_query::queries::set::is_empty_set(set_stmt.as_str()) {
+                    query_type = edgedb_query::QueryType::Select;
+                } else {
+                    query.push_str(&set_stmt);
+                }
+            });
+        }
 
         if has_result {
             edgeql_statements.push(quote! { query.push_str(" )"); });
@@ -91,6 +159,18 @@ impl Query for UpdateQuery {
             has_result
         })
     }
+
+    fn to_token_stream(&self) -> syn::Result<proc_macro::TokenStream> {
+        let query_quote = self.to_impl_builder()?.build();
+
+        let versioned_quote = self.versioned_impl_quote();
+
+        Ok(quote! {
+            #query_quote
+
+            #versioned_quote
+        }.into())
+    }
 }
 
 impl Parse for UpdateQuery {
@@ -101,7 +181,7 @@ impl Parse for UpdateQuery {
 
         let field_iter = strukt.fields.iter();
 
-        query.filter_statement = filters_from_fields(field_iter.clone(), vec![SET, SETS], FilterRequiredQuery::Update, INVALID_UPDATE_TAG)?;
+        query.filter_statement = filters_from_fields(field_iter.clone(), vec![SET, SETS, EXPR], FilterRequiredQuery::Update, INVALID_UPDATE_TAG)?;
 
         query.set_statement = sets_from_fields(field_iter, vec![FILTER, FILTERS, AND_FILTER, OR_FILTER], false,INVALID_UPDATE_TAG)?;
 
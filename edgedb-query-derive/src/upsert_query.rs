@@ -0,0 +1,133 @@
+use std::convert::TryFrom;
+
+use quote::{quote, ToTokens};
+use syn::{Ident, ItemStruct};
+use syn::parse::{Parse, ParseStream};
+
+use crate::builders::impl_builder::QueryImplBuilder;
+use crate::constants::{AT, OVERWRITE, UNLESS_CONFLICT, UPSERT_NO_UNLESS_CONFLICT, UPSERT_NOTHING_TO_OVERWRITE};
+use crate::insert_query::{InsertQuery, InsertStatement};
+use crate::meta_data::{try_get_meta, UpsertMetaData};
+use crate::queries::Query;
+use crate::utils::attributes_utils::has_attribute;
+
+// region UpsertQuery
+#[derive(Debug, Clone)]
+pub struct UpsertQuery {
+    pub insert: InsertQuery,
+    pub on: Vec<String>,
+    pub overwritten: Vec<Ident>,
+}
+
+impl UpsertQuery {
+    pub fn with_meta(&mut self, meta: UpsertMetaData) -> syn::Result<&mut Self> {
+        self.insert.with_meta(meta.query);
+
+        let columns = self.insert.statements
+            .iter()
+            .filter_map(|stmt| stmt.column_name())
+            .collect::<Vec<String>>();
+
+        let unknowns = meta.on.iter()
+            .filter(|c| !columns.contains(c) && !columns.contains(&format!("{AT}{c}")))
+            .collect::<Vec<&String>>();
+
+        if !unknowns.is_empty() {
+            return Err(syn::Error::new_spanned(&self.insert.ident, format!("Following names are not column names : {unknowns:#?}")));
+        }
+
+        self.on = meta.on.into_iter()
+            .map(|o| {
+                let formatted = format!("{AT}{o}");
+                if columns.contains(&formatted) { formatted } else { o }
+            })
+            .collect();
+
+        Ok(self)
+    }
+
+    /// returns the statements of the fields overwritten on conflict :
+    /// the fields marked #[overwrite] or, when none is marked, all the simple non key fields
+    fn overwritten_statements(&self) -> Vec<&InsertStatement> {
+        if self.overwritten.is_empty() {
+            self.insert.statements.iter()
+                .filter(|stmt| matches!(stmt, InsertStatement::SimpleField(_)))
+                .filter(|stmt| !stmt.column_name().map(|c| self.on.contains(&c)).unwrap_or(false))
+                .collect()
+        } else {
+            self.insert.statements.iter()
+                .filter(|stmt| self.overwritten.contains(stmt.field_ident()))
+                .collect()
+        }
+    }
+}
+
+impl Query for UpsertQuery {
+    fn get_param_labels(&self) -> Vec<(Ident, String)> {
+        self.insert.get_param_labels()
+    }
+
+    fn to_impl_builder(&self) -> syn::Result<QueryImplBuilder> {
+        let meta = try_get_meta(&self.insert.ident, || self.insert.meta.clone())?;
+
+        let table_name = meta.table_name();
+
+        let overwritten = self.overwritten_statements();
+
+        if overwritten.is_empty() {
+            return Err(syn::Error::new_spanned(&self.insert.ident, UPSERT_NOTHING_TO_OVERWRITE));
+        }
+
+        let set_stmts = overwritten.iter().map(|stmt| stmt.query_statement_quote());
+
+        let on_fields = self.on.join(",");
+
+        let conflict_quote = quote! {
+            let set_stmt = {
+                let mut query = String::new();
+                #(#set_stmts)*
+                query
+            };
+            let qn = #on_fields.split(",").collect::<Vec<&str>>();
+            let c_q = edgedb_query::queries::conflict::parse_upsert(qn, #table_name, set_stmt.as_str());
+            query.push_str(c_q.as_str());
+        };
+
+        self.insert.impl_builder_with_conflict(Some(conflict_quote))
+    }
+}
+
+impl Parse for UpsertQuery {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let strukt = input.parse::<ItemStruct>()?;
+
+        let mut query = UpsertQuery {
+            insert: InsertQuery::new(strukt.ident.clone()),
+            on: vec![],
+            overwritten: vec![],
+        };
+
+        for field in &strukt.fields {
+            if has_attribute(field, UNLESS_CONFLICT) {
+                return Err(syn::Error::new_spanned(field.to_token_stream(), UPSERT_NO_UNLESS_CONFLICT));
+            }
+
+            let mut field = field.clone();
+
+            if has_attribute(&field, OVERWRITE) {
+                query.overwritten.extend(field.ident.clone());
+                field.attrs.retain(|att| !att.path.is_ident(OVERWRITE));
+            }
+
+            query.insert.statements.push(InsertStatement::try_from(&field)?);
+        }
+
+        query.insert.attach_link_properties()?;
+
+        query.check_duplicate_parameter_labels()?;
+
+        Ok(query)
+    }
+}
+
+// endregion UpsertQuery
@@ -1,5 +1,8 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
+use syn::Type;
+use crate::constants::{SCALAR_TYPE, VEC};
+use crate::utils::type_utils::{get_scalar, get_type, is_type_name};
 
 pub fn format_scalar() -> TokenStream {
     quote! {
@@ -14,6 +17,72 @@ pub fn format_scalar() -> TokenStream {
         }
 }
 
+/// Quote the computation of a field's scalar into a __scalar__ variable, from the field type __ToEdgeScalar__ implementation
+///
+/// __field_ty__ : the field type (optional or not)
+pub fn scalar_quote(field_ty: &Type) -> TokenStream {
+    let format_scalar = format_scalar();
+    let ty = get_type(field_ty);
+
+    let scalar_quote = if is_type_name(field_ty, VEC) {
+        quote! { let mut scalar = <#ty as edgedb_query::ToEdgeScalar>::vec_scalar(); }
+    } else {
+        quote! { let mut scalar = <#ty as edgedb_query::ToEdgeScalar>::scalar(); }
+    };
+
+    quote! {
+        #scalar_quote
+        #format_scalar;
+    }
+}
+
+/// Quote a compile time check that each custom scalar type within a field type implements __EdgedbScalar__
+///
+/// __field_ty__ : the field type (optional or not, a tuple's elements being checked one by one)
+pub fn custom_scalar_check_quote(field_ty: &Type) -> TokenStream {
+    let ty = get_type(field_ty);
+
+    if let Type::Tuple(tuple) = &ty {
+        let checks = tuple.elems.iter().map(custom_scalar_check_quote);
+        return quote!(#(#checks)*);
+    }
+
+    if !matches!(get_scalar(&ty), Ok(s) if s == SCALAR_TYPE) {
+        return quote!();
+    }
+
+    quote! {
+        const _: () = {
+            use std::marker::PhantomData;
+            struct ImplEdgedbScalar<T: edgedb_query::EdgedbScalar>(PhantomData<T>);
+            let _ = ImplEdgedbScalar(PhantomData::<#ty>);
+        };
+    }
+}
+
+/// Quote a statement as a String expression, whose scalar placeholder (if any) is replaced by the field scalar
+///
+/// __stmt__ : the statement
+///
+/// __field_ty__ : the type of the field the statement is built from
+pub fn resolved_statement_quote(stmt: String, field_ty: &Type) -> TokenStream {
+    if !stmt.contains(SCALAR_TYPE) {
+        return quote!(#stmt.to_owned());
+    }
+
+    let scalar_quote = scalar_quote(field_ty);
+    let scalar_type = SCALAR_TYPE.to_string();
+    let custom_scalar_check = custom_scalar_check_quote(field_ty);
+
+    quote! {
+        {
+            #custom_scalar_check
+            #scalar_quote
+            #stmt.replace(#scalar_type, scalar.as_str())
+        }
+    }
+}
+
 pub fn element_shape(f_ident: Ident, f_name: impl Into<String>, field_is_option: bool) -> TokenStream {
     let f_name = f_name.into();
 
@@ -41,38 +110,49 @@ pub fn element_value(f_name: Ident, field_is_option: bool) -> TokenStream {
     if field_is_option {
         quote! {
                 if let Some(v) = &self.#f_name {
-                    fields.push(Some(v.to_edge_value()));
+                    fields.push(Some(v.try_to_edge_value()?));
                 }
             }
     } else {
         quote! {
-                fields.push(Some(self.#f_name.to_edge_value()));
+                fields.push(Some(self.#f_name.try_to_edge_value()?));
             }
     }
 }
 
 pub fn nested_element_shape(f_ident: Ident) -> TokenStream {
+    element_shape_quote(f_ident, quote!(e.name.clone()))
+}
+
+/// Quote the shape of a nested query whose parameters are prefixed with the field name
+pub fn prefixed_nested_element_shape(f_ident: Ident) -> TokenStream {
+    let prefix = f_ident.to_string();
+    element_shape_quote(f_ident, quote!(format!("{}_{}", #prefix, e.name)))
+}
+
+fn element_shape_quote(f_ident: Ident, name: TokenStream) -> TokenStream {
     quote! {
 
-        match self.#f_ident.to_edge_value() {
+        match self.#f_ident.try_to_edge_value()? {
             edgedb_protocol::value::Value::Object { shape, fields } => {
                 let elements = &shape.elements;
                 elements.iter().for_each(|e| {
+                    let name = #name;
                     elmt_nb += 1;
                     shapes.push(edgedb_protocol::descriptors::ShapeElement {
                         flag_implicit: false,
                         flag_link_property: false,
                         flag_link: false,
                         cardinality: e.cardinality,
-                        name: e.name.clone(),
+                        name: name.clone(),
                         type_pos: edgedb_protocol::descriptors::TypePos(elmt_nb as u16),
                     });
 
 
-                    if (element_names.contains(&e.name.clone())) {
-                        panic!("Duplicate query parameter name found : {}", e.name.clone())
+                    if (element_names.contains(&name)) {
+                        panic!("Duplicate query parameter name found : {}", name)
                     } else {
-                        element_names.push(e.name.clone());
+                        element_names.push(name);
                     }
                 });
             }
@@ -83,7 +163,7 @@ pub fn nested_element_shape(f_ident: Ident) -> TokenStream {
 
 pub fn nested_element_value(f_ident: Ident) -> TokenStream {
     quote! {
-        match self.#f_ident.to_edge_value() {
+        match self.#f_ident.try_to_edge_value()? {
             edgedb_protocol::value::Value::Object { shape, fields: fs } => {
                 fs.iter().for_each(|f| fields.push(f.clone()));
             }
@@ -92,10 +172,16 @@ pub fn nested_element_value(f_ident: Ident) -> TokenStream {
     }
 }
 
+pub fn nested_many_element_value(f_ident: Ident) -> TokenStream {
+    quote! {
+        fields.push(Some(edgedb_query::queries::bulk::bulk_items_value(&self.#f_ident)?));
+    }
+}
+
 pub fn conflict_element_shape(f_name: Ident) -> TokenStream {
     quote! {
         if let Some(q) = self.#f_name.else_query() {
-             if let edgedb_protocol::value::Value::Object { shape, fields: f_fields } = q.to_edge_value() {
+             if let edgedb_protocol::value::Value::Object { shape, fields: f_fields } = q.try_to_edge_value()? {
 
                 let mut i = shapes.len() - 1;
 
@@ -128,9 +214,40 @@ pub fn conflict_element_shape(f_name: Ident) -> TokenStream {
 pub fn conflict_element_value(f_ident: Ident) -> TokenStream {
     quote! {
         if let Some(q) = self.#f_ident.else_query() {
-            if let edgedb_protocol::value::Value::Object { shape, fields: f_fields } = q.to_edge_value() {
+            if let edgedb_protocol::value::Value::Object { shape, fields: f_fields } = q.try_to_edge_value()? {
                 f_fields.iter().for_each(|ff| fields.push(ff.clone()));
             }
         }
     }
 }
+
+/// Build the statement selecting the targets of a typed link field (Link<T> or Links<T>) into a __link__ variable
+///
+/// __ty__ : the link type
+///
+/// __param__ : the query parameter holding the targets ids
+///
+/// __properties__ : the link properties fields (field ident, field is option, assignment statement expression)
+pub fn link_target_quote(ty: &syn::Type, param: impl Into<String>, properties: Vec<(Ident, bool, TokenStream)>) -> TokenStream {
+    let param = param.into();
+
+    let add_properties = properties.iter().map(|(f_ident, is_option, stmt)| {
+        if *is_option {
+            quote! {
+                if self.#f_ident.is_some() {
+                    properties.push(#stmt);
+                }
+            }
+        } else {
+            quote! { properties.push(#stmt); }
+        }
+    });
+
+    quote! {
+        let link = {
+            let mut properties: Vec<String> = vec![];
+            #(#add_properties)*
+            <#ty as edgedb_query::models::link::ToEdgeLink>::link_statement_with_properties(#param, properties.join(", ").as_str())
+        };
+    }
+}
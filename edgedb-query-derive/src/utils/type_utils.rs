@@ -1,9 +1,10 @@
 #![allow(unused)]
 
-use syn::{Field, Type, TypeTuple};
+use quote::ToTokens;
+use syn::{Field, GenericArgument, PathArguments, Type, TypeTuple};
 use syn::__private::bool;
 use edgedb_query::ToEdgeScalar;
-use crate::constants::{OPTION, VEC};
+use crate::constants::{BYTES_SCALAR, BYTES_TYPES, INVALID_RANGE_BOUND, LINK_TYPE, LINKS_TYPE, OPTION, RANGE_BOUND_SCALARS, RANGE_TYPE, SCALAR_TYPE, U8_TYPE, VEC};
 
 
 /// Check if a type name is equal to the  given name
@@ -14,7 +15,7 @@ use crate::constants::{OPTION, VEC};
 pub fn is_type_name(ty: &Type, name: &str) -> bool {
     match ty {
         Type::Path(ref p) => p.path.segments.len() == 1 && p.path.segments[0].ident == name,
-        Type::Tuple(_) => name == "()",
+        Type::Tuple(t) => name == "()" && t.elems.is_empty(),
         _ => false,
     }
 }
@@ -63,6 +64,56 @@ pub fn get_wrapped_type(ty: &Type, wrapper: &str) -> Type {
     }
 }
 
+/// Check if a type (optional or not) is a typed link reference (Link<T> or Links<T>)
+pub fn is_link_type(ty: &Type) -> bool {
+    let ty = get_type(ty);
+    is_scalar_type_name(&ty, LINK_TYPE) || is_scalar_type_name(&ty, LINKS_TYPE)
+}
+
+/// Check if a type (optional or not) is a multi link reference (Links<T>)
+pub fn is_links_type(ty: &Type) -> bool {
+    is_scalar_type_name(&get_type(ty), LINKS_TYPE)
+}
+
+/// Check if a type (optional or not) is a binary blob (Vec<u8>, Bytes or bytes::Bytes)
+pub fn is_bytes_type(ty: &Type) -> bool {
+    let ty = if is_type_name(ty, OPTION) { get_wrapped_type(ty, OPTION) } else { ty.clone() };
+
+    if is_type_name(&ty, VEC) {
+        is_type_name(&get_wrapped_type(&ty, VEC), U8_TYPE)
+    } else {
+        BYTES_TYPES.iter().any(|name| is_scalar_type_name(&ty, name))
+    }
+}
+
+/// Get the bound type of a range type (optional or not), whatever the Range path qualification
+///
+/// __ty__ : the type to check
+pub fn get_range_bound_type(ty: &Type) -> Option<Type> {
+    let ty = if is_type_name(ty, OPTION) { get_wrapped_type(ty, OPTION) } else { ty.clone() };
+
+    if let Type::Path(ref p) = ty {
+        let segment = p.path.segments.last()?;
+
+        if segment.ident != RANGE_TYPE {
+            return None;
+        }
+
+        if let syn::PathArguments::AngleBracketed(ref generic_args) = segment.arguments {
+            if let Some(syn::GenericArgument::Type(t)) = generic_args.args.first() {
+                return Some(t.clone());
+            }
+        }
+    }
+
+    None
+}
+
+/// Check if a type (optional or not) is a range (Range<T>)
+pub fn is_range_type(ty: &Type) -> bool {
+    get_range_bound_type(ty).is_some()
+}
+
 pub fn get_type( ty: &Type) -> Type {
     if is_type_name(ty, OPTION) {
         get_wrapped_type(ty, OPTION)
@@ -73,10 +124,90 @@ pub fn get_type( ty: &Type) -> Type {
     }
 }
 
+/// Check if a type matches a scalar_types() entry, the type path being the entry path or one of its suffixes
+/// (e.g. __Uuid__ and __uuid::Uuid__ match __uuid::Uuid__, but __my::Uuid__ does not).
+/// The generic arguments are compared the same way, an entry without generic arguments matching any of them.
+///
+/// __ty__ : the type to check
+///
+/// __name__ : the scalar_types() entry name
+pub fn is_scalar_type_name(ty: &Type, name: &str) -> bool {
+    match syn::parse_str::<Type>(name) {
+        Ok(expected) => type_path_matches(ty, &expected),
+        Err(_) => false,
+    }
+}
+
+fn type_path_matches(ty: &Type, expected: &Type) -> bool {
+    let (p, e) = match (ty, expected) {
+        (Type::Path(p), Type::Path(e)) => (p, e),
+        _ => return ty.to_token_stream().to_string() == expected.to_token_stream().to_string(),
+    };
+
+    let (segments, expected_segments) = (&p.path.segments, &e.path.segments);
+
+    if p.qself.is_some() || segments.len() > expected_segments.len() {
+        return false;
+    }
+
+    let suffix = expected_segments.iter().skip(expected_segments.len() - segments.len());
+
+    if !segments.iter().zip(suffix).all(|(s, e)| s.ident == e.ident) {
+        return false;
+    }
+
+    let (last, expected_last) = match (segments.last(), expected_segments.last()) {
+        (Some(last), Some(expected_last)) => (last, expected_last),
+        _ => return false,
+    };
+
+    match (&last.arguments, &expected_last.arguments) {
+        (_, PathArguments::None) => true,
+        (PathArguments::AngleBracketed(args), PathArguments::AngleBracketed(expected_args)) => {
+            args.args.len() == expected_args.args.len()
+                && args.args.iter().zip(expected_args.args.iter()).all(|(a, e)| match (a, e) {
+                    (GenericArgument::Type(a), GenericArgument::Type(e)) => type_path_matches(a, e),
+                    _ => false,
+                })
+        }
+        _ => false,
+    }
+}
+
 pub fn get_scalar(ty: &Type) -> syn::Result<String> {
+    if is_bytes_type(ty) {
+        return Ok(BYTES_SCALAR.to_owned());
+    }
+
+    if let Type::Tuple(tuple) = get_type(ty) {
+        if !tuple.elems.is_empty() {
+            let elements = tuple.elems.iter()
+                .map(|t| get_scalar(t).map(|s| s.strip_prefix('<').and_then(|s| s.strip_suffix('>')).unwrap_or(&s).to_owned()))
+                .collect::<syn::Result<Vec<String>>>()?;
+
+            if elements.iter().any(|e| e == SCALAR_TYPE) {
+                return Ok(SCALAR_TYPE.to_owned());
+            }
+
+            let scalar = format!("tuple<{}>", elements.join(", "));
+
+            return Ok(if is_type_name(ty, VEC) { format!("<array<{scalar}>>") } else { format!("<{scalar}>") });
+        }
+    }
+
+    if let Some(bound) = get_range_bound_type(ty) {
+        let bound_scalar = get_scalar(&bound)?.replace(['<', '>'], "");
+
+        return if RANGE_BOUND_SCALARS.contains(&bound_scalar.as_str()) {
+            Ok(format!("<range<{bound_scalar}>>"))
+        } else {
+            Err(syn::Error::new_spanned(bound, format!("{INVALID_RANGE_BOUND} {bound_scalar}")))
+        };
+    }
+
     scalar_types()
         .into_iter()
-        .find(|(t, _)| get_type_name(&get_type(ty)) == *t)
+        .find(|(t, _)| is_scalar_type_name(&get_type(ty), t))
         .map(|(_, s)| {
             if is_type_name(ty, VEC){
                 Ok(format!("<array<{s}>>"))
@@ -87,10 +218,16 @@ pub fn get_scalar(ty: &Type) -> syn::Result<String> {
             }
         })
         .unwrap_or_else(|| {
-            Err(syn::Error::new_spanned(
-                ty,
-                format!("Unsupported typo: {}", get_type_name(ty)),
-            ))
+            if let Type::Path(_) = get_type(ty) {
+                // a custom scalar (an #[edgedb_scalar] newtype) : its scalar is only known at runtime,
+                // the field type is checked to be an edgedb_query::EdgedbScalar by custom_scalar_check_quote
+                Ok(SCALAR_TYPE.to_owned())
+            } else {
+                Err(syn::Error::new_spanned(
+                    ty,
+                    format!("Unsupported typo: {}", get_type_name(ty)),
+                ))
+            }
         })
 }
 
@@ -109,7 +246,7 @@ pub fn match_scalar(ty: &Type, scalar: impl Into<String> ) -> syn::Result<()> {
         )
     )?;
 
-    let scalar = if is_type_name(ty, VEC) { format!("<array<{sc}>>") } else { sc };
+    let scalar = if is_type_name(ty, VEC) && !is_bytes_type(ty) { format!("<array<{sc}>>") } else { sc };
 
     if scalar_found.replace(['<', '>'], "") == scalar.replace(['<', '>'], "") {
         Ok(())
@@ -122,14 +259,14 @@ pub fn match_scalar(ty: &Type, scalar: impl Into<String> ) -> syn::Result<()> {
 }
 
 pub fn scalar_types() -> Vec<(&'static str, &'static str)>{
-    vec![
+    let mut types = vec![
         ("String", "str"),
         ("i8", "int16"),
         ("u8", "int16"),
         ("i16", "int16"),
-        ("u16", "int16"),
+        ("u16", "int32"),
         ("i32", "int32"),
-        ("u32", "int32"),
+        ("u32", "int64"),
         ("i64", "int64"),
         ("u64", "int64"),
         ("f32", "float32"),
@@ -137,12 +274,38 @@ pub fn scalar_types() -> Vec<(&'static str, &'static str)>{
         ("bool", "bool"),
         ("uuid::Uuid", "uuid"),
         ("serde_json::Value", "json"),
+        ("edgedb_query::Bytes", "bytes"),
+        ("edgedb_protocol::model::BigInt", "bigint"),
+        ("edgedb_protocol::model::Decimal", "decimal"),
+        ("edgedb_protocol::model::RelativeDuration", "cal::relative_duration"),
+        ("edgedb_protocol::model::DateDuration", "cal::date_duration"),
+        ("num_bigint::BigInt", "bigint"),
+        ("rust_decimal::Decimal", "decimal"),
+        ("edgedb_query::RustDecimal", "decimal"),
+        ("edgedb_query::Link", "uuid"),
+        ("edgedb_query::Links", "array<uuid>"),
+        ("()", ""),
+    ];
+
+    #[cfg(feature = "chrono")]
+    types.extend([
         ("chrono::DateTime<chrono::Utc>", "datetime"),
-        ("chrono::DateTime<chrono::Local>", "cal::local_datetime"),
-        ("chrono::Duration", "<duration>"),
+        ("chrono::DateTime<chrono::Local>", "datetime"),
+        ("chrono::NaiveDateTime", "cal::local_datetime"),
+        ("chrono::Duration", "duration"),
         ("chrono::Date<chrono::Local>", "cal::local_date"),
         ("chrono::NaiveTime", "cal::local_time"),
         ("chrono::NaiveDate", "cal::local_date"),
-        ("()", ""),
-    ]
+    ]);
+
+    #[cfg(feature = "time")]
+    types.extend([
+        ("time::OffsetDateTime", "datetime"),
+        ("time::PrimitiveDateTime", "cal::local_datetime"),
+        ("time::Duration", "duration"),
+        ("time::Date", "cal::local_date"),
+        ("time::Time", "cal::local_time"),
+    ]);
+
+    types
 }
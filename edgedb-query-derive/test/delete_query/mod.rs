@@ -2,7 +2,9 @@
 #[cfg(test)]
 mod delete {
     use edgedb_protocol::value::Value;
-    use edgedb_query_derive::{delete_query, edgedb_filters};
+    use edgedb_query_derive::{delete_query, edgedb_filters, query_result};
+    use edgedb_query::queries::select::{OrderOptions, PageOptions, SelectOptions};
+    use uuid::Uuid;
     use edgedb_query::models::{edge_query::{ToEdgeQuery, EdgeQuery}};
 
     #[delete_query(module ="users", table="User")]
@@ -75,4 +77,64 @@ mod delete {
             assert!(false)
         }
     }
-}
\ No newline at end of file
+
+    #[query_result]
+    pub struct DeletedUser {
+        pub id: Uuid,
+        pub name: String,
+    }
+
+    #[delete_query(module ="users", table="User", result="DeletedUser")]
+    pub struct DeleteUsersByAgeReturning {
+        #[filter(operator="=")]
+        pub age: i16
+    }
+
+    #[test]
+    pub fn delete_users_with_result_test() {
+        let edge_query: EdgeQuery = DeleteUsersByAgeReturning { age: 25 }.to_edge_query();
+
+        assert_eq!(edge_query.query, "select ( delete users::User filter users::User.age = (select <int16>$age) ){id,name}");
+    }
+
+    #[delete_query(module ="users", table="User")]
+    pub struct DeleteUsersBatch {
+        #[filter(operator="<")]
+        pub age: i16,
+        #[options]
+        pub options: SelectOptions,
+    }
+
+    #[test]
+    pub fn delete_users_batch_test() {
+        let del_users = DeleteUsersBatch {
+            age: 18,
+            options: SelectOptions {
+                order_options: Some(OrderOptions {
+                    order_by: "created_at".to_owned(),
+                    order_direction: None,
+                }),
+                page_options: Some(PageOptions {
+                    limit: 100,
+                    offset: None,
+                }),
+            },
+        };
+
+        let edge_query: EdgeQuery = del_users.to_edge_query();
+
+        assert_eq!(
+            edge_query.query,
+            "delete (select users::User filter users::User.age < (select <int16>$age) order by users::User.created_at asc limit 100)"
+        );
+
+        if let Some(Value::Object { shape, fields }) = edge_query.args {
+            crate::test_utils::check_shape(&shape, vec!["age"]);
+            assert_eq!(fields, vec![
+                Some(Value::Int16(18))
+            ])
+        } else {
+            unreachable!()
+        }
+    }
+}
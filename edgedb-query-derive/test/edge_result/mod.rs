@@ -4,6 +4,10 @@ mod result {
     use edgedb_query_derive::query_result;
     use edgedb_query::{EdgeResult, ToEdgeShape};
     use uuid::Uuid;
+    use edgedb_protocol::codec::{STD_STR, STD_UUID};
+    use edgedb_protocol::descriptors::OutputTypedesc;
+    use crate::test_utils::{decode_result, encode_array, encode_elements};
+    use edgedb_query::test_support::TypeDescBuilder;
 
 
     #[query_result]
@@ -163,4 +167,225 @@ mod result {
         let shape = UserWithDefault::shape();
         assert_eq!(shape, "{id,login := (select <str>str_upper(.pseudo)) ?? (select <str>'john')}")
     }
-}
\ No newline at end of file
+
+    #[query_result]
+    pub struct BaseFields {
+        pub id: Uuid,
+        pub created_at: String,
+        pub owner: Friend,
+    }
+
+    #[query_result]
+    pub struct PostWithFlatten {
+        #[flatten]
+        pub base: BaseFields,
+        pub title: String,
+    }
+
+    #[query_result(extends = "BaseFields")]
+    pub struct PostWithExtends {
+        pub title: String,
+    }
+
+    #[test]
+    pub fn test_flatten_shape() {
+        let shape = PostWithFlatten::shape();
+        let fields = PostWithFlatten::returning_fields();
+        assert_eq!(shape, "{id,created_at,owner : {id,surname},title}");
+        assert_eq!(fields, vec!["id", "created_at", "owner", "title"]);
+    }
+
+    #[test]
+    pub fn test_extends_shape() {
+        let shape = PostWithExtends::shape();
+        let fields = PostWithExtends::returning_fields();
+        assert_eq!(shape, "{id,created_at,owner : {id,surname},title}");
+        assert_eq!(fields, vec!["id", "created_at", "owner", "title"]);
+
+        let post = PostWithExtends::default();
+        assert_eq!(post.created_at, post.base.created_at);
+    }
+
+    fn post_descriptor() -> OutputTypedesc {
+        let mut builder = TypeDescBuilder::default();
+        let uuid = builder.base_scalar(STD_UUID);
+        let str = builder.base_scalar(STD_STR);
+        let owner = builder.object_shape(false, &[("id", uuid), ("surname", str)]);
+        builder.object_shape(false, &[("id", uuid), ("created_at", str), ("owner", owner), ("title", str)]);
+        builder.build()
+    }
+
+    fn post_buffer(id: Uuid, owner_id: Uuid) -> Vec<u8> {
+        encode_elements(vec![
+            Some(id.as_bytes().to_vec()),
+            Some(b"2022-01-01".to_vec()),
+            Some(encode_elements(vec![Some(owner_id.as_bytes().to_vec()), Some(b"Joe".to_vec())])),
+            Some(b"Hello".to_vec()),
+        ])
+    }
+
+    #[test]
+    pub fn decode_flatten_test() {
+        let (id, owner_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let post: PostWithFlatten = decode_result(&post_descriptor(), &post_buffer(id, owner_id));
+
+        assert_eq!(post.base.id, id);
+        assert_eq!(post.base.created_at, "2022-01-01");
+        assert_eq!(post.base.owner.id, owner_id);
+        assert_eq!(post.base.owner.surname, "Joe");
+        assert_eq!(post.title, "Hello");
+    }
+
+    #[test]
+    pub fn decode_extends_test() {
+        let (id, owner_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let post: PostWithExtends = decode_result(&post_descriptor(), &post_buffer(id, owner_id));
+
+        assert_eq!(post.id, id);
+        assert_eq!(post.created_at, "2022-01-01");
+        assert_eq!(post.owner.surname, "Joe");
+        assert_eq!(post.title, "Hello");
+    }
+
+    #[query_result]
+    pub struct Comment {
+        pub id: Uuid,
+        pub text: String,
+        #[link(depth = 2)]
+        pub replies: Vec<Comment>,
+    }
+
+    #[query_result]
+    pub struct Employee {
+        pub id: Uuid,
+        pub name: String,
+        #[link(depth = 1)]
+        pub manager: Option<Box<Employee>>,
+    }
+
+    #[test]
+    pub fn test_recursive_vec_shape() {
+        let shape = Comment::shape();
+        assert_eq!(shape, "{id,text,replies : {id,text,replies : {id,text}}}");
+    }
+
+    #[test]
+    pub fn test_recursive_box_shape() {
+        let shape = Employee::shape();
+        assert_eq!(shape, "{id,name,manager : {id,name}}");
+    }
+
+    #[test]
+    pub fn decode_link_depth_test() {
+        let mut builder = TypeDescBuilder::default();
+        let uuid = builder.base_scalar(STD_UUID);
+        let str = builder.base_scalar(STD_STR);
+        let deepest = builder.object_shape(false, &[("id", uuid), ("text", str)]);
+        let deepest_replies = builder.set(deepest);
+        let reply = builder.object_shape(false, &[("id", uuid), ("text", str), ("replies", deepest_replies)]);
+        let replies = builder.set(reply);
+        builder.object_shape(false, &[("id", uuid), ("text", str), ("replies", replies)]);
+        let desc = builder.build();
+
+        let (id, reply_id, deepest_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        let deepest_buf = encode_elements(vec![Some(deepest_id.as_bytes().to_vec()), Some(b"Indeed".to_vec())]);
+        let reply_buf = encode_elements(vec![
+            Some(reply_id.as_bytes().to_vec()),
+            Some(b"Agreed".to_vec()),
+            Some(encode_array(vec![deepest_buf])),
+        ]);
+        let buf = encode_elements(vec![
+            Some(id.as_bytes().to_vec()),
+            Some(b"Hello".to_vec()),
+            Some(encode_array(vec![reply_buf])),
+        ]);
+
+        let comment: Comment = decode_result(&desc, &buf);
+
+        assert_eq!(comment.id, id);
+        assert_eq!(comment.text, "Hello");
+        assert_eq!(comment.replies.len(), 1);
+
+        let reply = &comment.replies[0];
+        assert_eq!(reply.id, reply_id);
+        assert_eq!(reply.text, "Agreed");
+        assert_eq!(reply.replies.len(), 1);
+
+        let deepest = &reply.replies[0];
+        assert_eq!(deepest.id, deepest_id);
+        assert_eq!(deepest.text, "Indeed");
+        assert!(deepest.replies.is_empty());
+    }
+
+    #[query_result(free_object = true)]
+    pub struct Label {
+        pub name: String,
+    }
+
+    #[query_result]
+    pub struct Category {
+        #[flatten]
+        pub label: Label,
+        #[link(depth = 1)]
+        pub children: Vec<Category>,
+    }
+
+    #[test]
+    pub fn decode_link_depth_with_implicit_id_test() {
+        let mut builder = TypeDescBuilder::default();
+        let str = builder.base_scalar(STD_STR);
+        let child = builder.object_shape(true, &[("name", str)]);
+        let children = builder.set(child);
+        builder.object_shape(true, &[("name", str), ("children", children)]);
+        let desc = builder.build();
+
+        let child_buf = encode_elements(vec![Some(Uuid::new_v4().as_bytes().to_vec()), Some(b"Rust".to_vec())]);
+        let buf = encode_elements(vec![
+            Some(Uuid::new_v4().as_bytes().to_vec()),
+            Some(b"Languages".to_vec()),
+            Some(encode_array(vec![child_buf])),
+        ]);
+
+        let category: Category = decode_result(&desc, &buf);
+
+        assert_eq!(category.label.name, "Languages");
+        assert_eq!(category.children.len(), 1);
+        assert_eq!(category.children[0].label.name, "Rust");
+        assert!(category.children[0].children.is_empty());
+    }
+
+    #[test]
+    pub fn decode_optional_link_depth_test() {
+        let mut builder = TypeDescBuilder::default();
+        let uuid = builder.base_scalar(STD_UUID);
+        let str = builder.base_scalar(STD_STR);
+        let manager = builder.object_shape(false, &[("id", uuid), ("name", str)]);
+        builder.object_shape(false, &[("id", uuid), ("name", str), ("manager", manager)]);
+        let desc = builder.build();
+
+        let (id, manager_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let buf = encode_elements(vec![
+            Some(id.as_bytes().to_vec()),
+            Some(b"Joe".to_vec()),
+            Some(encode_elements(vec![Some(manager_id.as_bytes().to_vec()), Some(b"Jane".to_vec())])),
+        ]);
+
+        let employee: Employee = decode_result(&desc, &buf);
+
+        assert_eq!(employee.name, "Joe");
+        let manager = employee.manager.expect("manager is decoded");
+        assert_eq!(manager.id, manager_id);
+        assert_eq!(manager.name, "Jane");
+        assert!(manager.manager.is_none());
+
+        let buf = encode_elements(vec![Some(id.as_bytes().to_vec()), Some(b"Joe".to_vec()), None]);
+
+        let employee: Employee = decode_result(&desc, &buf);
+
+        assert!(employee.manager.is_none());
+    }
+}
@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod scalar {
+    use edgedb_protocol::value::Value;
+    use edgedb_query::{EdgedbScalar, ToEdgeScalar, ToEdgeValue};
+    use edgedb_query::models::edge_query::ToEdgeQuery;
+    use edgedb_query_derive::{edgedb_scalar, insert_query, query_result, select_query, update_query};
+    use uuid::Uuid;
+    use crate::test_utils::check_shape;
+
+    #[edgedb_scalar(name = "default::Email")]
+    #[derive(Debug, Clone, Default)]
+    pub struct Email(pub String);
+
+    #[edgedb_scalar(name = "default::Cents")]
+    #[derive(Debug, Clone, Default)]
+    pub struct Cents(pub i64);
+
+    pub mod money {
+        use edgedb_query_derive::edgedb_scalar;
+
+        #[edgedb_scalar(name = "default::Money")]
+        #[derive(Debug, Clone, Default)]
+        pub struct Decimal(pub String);
+    }
+
+    #[test]
+    pub fn custom_scalar_test() {
+        assert_eq!(Email::scalar(), "<default::Email>");
+        assert_eq!(Cents::scalar(), "<default::Cents>");
+    }
+
+    #[test]
+    pub fn custom_scalar_marker_test() {
+        fn custom_scalar<T: EdgedbScalar>() -> String {
+            T::scalar()
+        }
+
+        assert_eq!(custom_scalar::<Email>(), "<default::Email>");
+        assert_eq!(custom_scalar::<Cents>(), "<default::Cents>");
+    }
+
+    #[test]
+    pub fn custom_scalar_value_test() {
+        let email = Email("john@doe.com".to_owned());
+
+        assert_eq!(email.to_edge_value(), Value::Str("john@doe.com".to_owned()));
+        assert_eq!(Cents(1250).to_edge_value(), Value::Int64(1250));
+    }
+
+    #[query_result]
+    pub struct AccountResult {
+        pub id: Uuid,
+        pub email: Email,
+        pub balance: Cents,
+    }
+
+    #[insert_query(module = "bank", table = "Account", result = "AccountResult")]
+    pub struct InsertAccount {
+        pub email: Email,
+        pub balance: Cents,
+    }
+
+    #[test]
+    pub fn insert_custom_scalar_test() {
+        let q = InsertAccount {
+            email: Email("john@doe.com".to_owned()),
+            balance: Cents(0),
+        };
+
+        let eq = q.to_edge_query();
+
+        let expected_query = r#"
+            select (
+                insert bank::Account {
+                    email := (select <default::Email>$email),
+                    balance := (select <default::Cents>$balance),
+                }
+            ) {id,email,balance}
+        "#.to_owned().replace('\n', "");
+
+        assert_eq!(eq.query.replace(' ', ""), expected_query.replace(' ', ""));
+
+        if let Some(Value::Object { shape, fields }) = eq.args {
+            check_shape(&shape, vec!["email", "balance"]);
+
+            assert_eq!(fields, vec![Some(Value::Str("john@doe.com".to_owned())), Some(Value::Int64(0))]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[select_query(module = "bank", table = "Account", result = "AccountResult")]
+    pub struct FindAccountByEmail {
+        #[filter(operator = "=")]
+        pub email: Email,
+    }
+
+    #[test]
+    pub fn filter_custom_scalar_test() {
+        let q = FindAccountByEmail {
+            email: Email("john@doe.com".to_owned()),
+        };
+
+        let eq = q.to_edge_query();
+
+        assert_eq!(eq.query, "select bank::Account {id,email,balance} filter bank::Account.email = (select <default::Email>$email)");
+    }
+
+    #[update_query(module = "bank", table = "Account")]
+    pub struct CreditAccount {
+        pub balance: Cents,
+
+        #[filter(operator = "=")]
+        pub email: Email,
+    }
+
+    #[test]
+    pub fn set_custom_scalar_test() {
+        let q = CreditAccount {
+            balance: Cents(1250),
+            email: Email("john@doe.com".to_owned()),
+        };
+
+        let eq = q.to_edge_query();
+
+        let expected_query = r#"
+            update bank::Account
+            filter bank::Account.email = (select <default::Email>$email)
+            set {
+                balance := (select <default::Cents>$balance)
+            }
+        "#.to_owned().replace('\n', "");
+
+        assert_eq!(eq.query.replace(' ', ""), expected_query.replace(' ', ""));
+    }
+
+    #[insert_query(module = "bank", table = "Transfer")]
+    pub struct InsertTransfer {
+        pub amount: money::Decimal,
+        pub fee: edgedb_protocol::model::BigInt,
+    }
+
+    #[test]
+    pub fn custom_scalar_named_as_builtin_test() {
+        let q = InsertTransfer {
+            amount: money::Decimal("12.50".to_owned()),
+            fee: edgedb_protocol::model::BigInt::from(0_i64),
+        };
+
+        assert_eq!(
+            q.to_edge_query().query,
+            "insert bank::Transfer {amount := (select <default::Money>$amount), fee := (select <bigint>$fee), }"
+        );
+    }
+}
@@ -0,0 +1,176 @@
+#[cfg(test)]
+mod tuple {
+    use edgedb_protocol::codec::{STD_FLOAT64, STD_INT32, STD_STR, STD_UUID};
+    use edgedb_protocol::value::Value;
+    use edgedb_query::{ToEdgeScalar, ToEdgeValue};
+    use edgedb_query::models::edge_query::ToEdgeQuery;
+    use edgedb_query_derive::{edgedb_tuple, insert_query, query_result, select_query, update_query};
+    use uuid::Uuid;
+    use crate::test_utils::{check_shape, decode_result, encode_elements};
+    use edgedb_query::test_support::TypeDescBuilder;
+
+    #[edgedb_tuple]
+    pub struct Point {
+        pub x: f64,
+        pub y: f64,
+    }
+
+    #[test]
+    pub fn named_tuple_scalar_test() {
+        assert_eq!(Point::scalar(), "<tuple<x: float64, y: float64>>");
+        assert_eq!(<(i64, String)>::scalar(), "<tuple<int64, str>>");
+        assert_eq!(<(i64, Vec<String>)>::scalar(), "<tuple<int64, array<str>>>");
+    }
+
+    #[test]
+    pub fn named_tuple_value_test() {
+        let point = Point { x: 1.0, y: 2.5 };
+
+        if let Value::NamedTuple { shape, fields } = point.to_edge_value() {
+            let names = shape.elements.iter().map(|e| e.name.clone()).collect::<Vec<String>>();
+
+            assert_eq!(names, vec!["x", "y"]);
+            assert_eq!(fields, vec![Value::Float64(1.0), Value::Float64(2.5)]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    pub fn tuple_value_test() {
+        assert_eq!((1_i64, "a".to_owned()).to_edge_value(), Value::Tuple(vec![Value::Int64(1), Value::Str("a".to_owned())]));
+    }
+
+    #[query_result]
+    pub struct PlaceResult {
+        pub id: Uuid,
+        pub code: (String, i32),
+        pub position: Point,
+    }
+
+    #[test]
+    pub fn decode_tuple_result_test() {
+        let mut builder = TypeDescBuilder::default();
+        let uuid = builder.base_scalar(STD_UUID);
+        let str = builder.base_scalar(STD_STR);
+        let int32 = builder.base_scalar(STD_INT32);
+        let float64 = builder.base_scalar(STD_FLOAT64);
+        let code = builder.tuple(&[str, int32]);
+        let position = builder.named_tuple(&[("x", float64), ("y", float64)]);
+        builder.object_shape(false, &[("id", uuid), ("code", code), ("position", position)]);
+        let desc = builder.build();
+
+        let id = Uuid::new_v4();
+
+        let buf = encode_elements(vec![
+            Some(id.as_bytes().to_vec()),
+            Some(encode_elements(vec![Some(b"FR".to_vec()), Some(75_i32.to_be_bytes().to_vec())])),
+            Some(encode_elements(vec![Some(48.85_f64.to_be_bytes().to_vec()), Some(2.35_f64.to_be_bytes().to_vec())])),
+        ]);
+
+        let place: PlaceResult = decode_result(&desc, &buf);
+
+        assert_eq!(place.id, id);
+        assert_eq!(place.code, ("FR".to_owned(), 75));
+        assert_eq!((place.position.x, place.position.y), (48.85, 2.35));
+    }
+
+    #[test]
+    pub fn named_tuple_descriptor_mismatch_test() {
+        let mut builder = TypeDescBuilder::default();
+        let float64 = builder.base_scalar(STD_FLOAT64);
+        builder.named_tuple(&[("y", float64), ("x", float64)]);
+        let desc = builder.build();
+
+        assert!(<Point as edgedb_protocol::queryable::Queryable>::check_descriptor(&desc.as_queryable_context(), desc.root_pos().unwrap()).is_err());
+    }
+
+    #[insert_query(module = "maps", table = "Place", result = "PlaceResult")]
+    pub struct InsertPlace {
+        pub code: (String, i32),
+        #[field(scalar = "tuple<x: float64, y: float64>")]
+        pub position: Point,
+    }
+
+    #[test]
+    pub fn insert_tuple_test() {
+        let q = InsertPlace {
+            code: ("FR".to_owned(), 75),
+            position: Point { x: 48.85, y: 2.35 },
+        };
+
+        let eq = q.to_edge_query();
+
+        let expected_query = r#"
+            select (
+                insert maps::Place {
+                    code := (select <tuple<str, int32>>$code),
+                    position := (select <tuple<x: float64, y: float64>>$position),
+                }
+            ) {id,code,position}
+        "#.to_owned().replace('\n', "");
+
+        assert_eq!(eq.query.replace(' ', ""), expected_query.replace(' ', ""));
+
+        if let Some(Value::Object { shape, fields }) = eq.args {
+            check_shape(&shape, vec!["code", "position"]);
+
+            assert_eq!(fields[0], Some(Value::Tuple(vec![Value::Str("FR".to_owned()), Value::Int32(75)])));
+            assert!(matches!(fields[1], Some(Value::NamedTuple { .. })));
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[select_query(module = "maps", table = "Place", result = "PlaceResult")]
+    pub struct FindPlaceByCode {
+        #[filter(operator = "=")]
+        pub code: (String, i32),
+    }
+
+    #[test]
+    pub fn filter_tuple_test() {
+        let q = FindPlaceByCode {
+            code: ("FR".to_owned(), 75),
+        };
+
+        let eq = q.to_edge_query();
+
+        assert_eq!(eq.query, "select maps::Place {id,code,position} filter maps::Place.code = (select <tuple<str, int32>>$code)");
+    }
+
+    #[update_query(module = "maps", table = "Place")]
+    pub struct MovePlace {
+        #[field(scalar = "tuple<x: float64, y: float64>")]
+        pub position: Point,
+
+        #[filter(operator = "=")]
+        pub code: (String, i32),
+    }
+
+    #[test]
+    pub fn set_named_tuple_test() {
+        let q = MovePlace {
+            position: Point { x: 0.0, y: 0.0 },
+            code: ("FR".to_owned(), 75),
+        };
+
+        let eq = q.to_edge_query();
+
+        let expected_query = r#"
+            update maps::Place
+            filter maps::Place.code = (select <tuple<str, int32>>$code)
+            set {
+                position := (select <tuple<x: float64, y: float64>>$position)
+            }
+        "#.to_owned().replace('\n', "");
+
+        assert_eq!(eq.query.replace(' ', ""), expected_query.replace(' ', ""));
+
+        if let Some(Value::Object { shape, .. }) = eq.args {
+            check_shape(&shape, vec!["code", "position"]);
+        } else {
+            unreachable!()
+        }
+    }
+}
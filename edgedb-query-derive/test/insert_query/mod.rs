@@ -179,4 +179,621 @@ mod insert {
             "insert default::Person {name := (select <str>$name), binome := (select detached default::Person filter default::Person.binome.name = (select <str>$binome)), }"
         )
     }
+
+    #[insert_query(table="City")]
+    pub struct InsertCity {
+        pub name: String,
+        pub modern_name: Option<String>,
+        #[unless_conflict(on="name")]
+        pub conflict: edgedb_query::queries::conflict::UnlessConflict,
+    }
+
+    #[test]
+    fn insert_many_cities_test() {
+        let cities = edgedb_query::InsertMany::new(vec![
+            InsertCity {
+                name: "Munich".to_owned(),
+                modern_name: None,
+                conflict: edgedb_query::queries::conflict::UnlessConflict,
+            },
+            InsertCity {
+                name: "Buda-Pesth".to_owned(),
+                modern_name: Some("Budapest".to_owned()),
+                conflict: edgedb_query::queries::conflict::UnlessConflict,
+            },
+        ]);
+
+        let query = cities.to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "for item in json_array_unpack(<json>$items) union (insert default::City {name := (<str>item['name']), modern_name := (<str>json_get(item, 'modern_name')), } unless conflict on .name)"
+        );
+
+        if let Some(Value::Object { shape, fields }) = query.args {
+            crate::test_utils::check_shape(&shape, vec!["items"]);
+            assert_eq!(fields, vec![
+                Some(Value::Json(r#"[{"name":"Munich"},{"modern_name":"Budapest","name":"Buda-Pesth"}]"#.to_owned()))
+            ])
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[insert_query(module = "events", table = "Meeting")]
+    pub struct InsertScheduledMeeting {
+        pub starts_at: chrono::DateTime<chrono::Utc>,
+        pub local_start: chrono::NaiveDateTime,
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn insert_many_datetimes_test() {
+        let starts_at = chrono::DateTime::parse_from_rfc3339("2022-01-31T10:30:00.123456Z").unwrap().with_timezone(&chrono::Utc);
+        let local_start = starts_at.naive_utc();
+
+        let query = edgedb_query::InsertMany::new(vec![InsertScheduledMeeting { starts_at, local_start }]).to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "for item in json_array_unpack(<json>$items) union (insert events::Meeting {starts_at := (<datetime>item['starts_at']), local_start := (<cal::local_datetime>item['local_start']), })"
+        );
+
+        let Some(Value::Object { fields, .. }) = query.args else { unreachable!() };
+        let Some(Value::Json(items)) = &fields[0] else { unreachable!() };
+        let items: serde_json::Value = serde_json::from_str(items).unwrap();
+
+        assert_eq!(items[0]["starts_at"], "2022-01-31T10:30:00.123456Z");
+        assert_eq!(items[0]["local_start"], "2022-01-31T10:30:00.123456");
+
+        let decoded = chrono::DateTime::parse_from_rfc3339(items[0]["starts_at"].as_str().unwrap()).unwrap();
+        assert_eq!(decoded, starts_at);
+
+        let decoded = chrono::NaiveDateTime::parse_from_str(items[0]["local_start"].as_str().unwrap(), "%Y-%m-%dT%H:%M:%S%.f").unwrap();
+        assert_eq!(decoded, local_start);
+    }
+
+    #[insert_query(module = "shop", table = "Product")]
+    pub struct InsertProduct {
+        pub name: String,
+        pub price: edgedb_protocol::model::Decimal,
+        pub stocks: Vec<edgedb_protocol::model::BigInt>,
+    }
+
+    #[test]
+    fn insert_many_decimals_test() {
+        let query = edgedb_query::InsertMany::<InsertProduct>::new(vec![]).to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "for item in json_array_unpack(<json>$items) union (insert shop::Product {name := (<str>item['name']), price := (<decimal><str>item['price']), stocks := (<array<bigint>><array<str>>item['stocks']), })"
+        );
+    }
+
+    #[insert_query(module = "users", table = "User", result = "UserResult")]
+    pub struct GetOrCreateUser {
+        #[field(column_name = "username")]
+        pub name: String,
+        #[unless_conflict(on = "username", else = "select")]
+        pub conflict: edgedb_query::queries::conflict::UnlessConflict,
+    }
+
+    #[test]
+    fn insert_unless_conflict_else_select_test() {
+        let query = GetOrCreateUser {
+            name: "Joe".to_owned(),
+            conflict: edgedb_query::queries::conflict::UnlessConflict,
+        }.to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "select ( insert users::User {username := (select <str>$name), } unless conflict on .username else ( select users::User )  ){id,name : {id,name}}"
+        );
+    }
+
+    #[insert_query(module = "blog", table = "Post")]
+    pub struct InsertPost {
+        pub title: String,
+        pub author: edgedb_query::Link<FindUser>,
+        pub reviewer: Option<edgedb_query::Link<FindUser>>,
+        pub readers: edgedb_query::Links<FindUser>,
+    }
+
+    #[test]
+    fn insert_post_with_links_test() {
+        let author = Uuid::new_v4();
+        let reader = Uuid::new_v4();
+
+        let query = InsertPost {
+            title: "EdgeDB".to_owned(),
+            author: edgedb_query::Link::new(author),
+            reviewer: None,
+            readers: edgedb_query::Links::new(vec![reader]),
+        }.to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "insert blog::Post {title := (select <str>$title), author := (select users::User filter .id = <uuid>$author), readers := (select users::User filter .id in array_unpack(<array<uuid>>$readers)), }"
+        );
+
+        if let Some(Value::Object { fields, .. }) = query.args {
+            assert_eq!(fields, vec![
+                Some(Value::Str("EdgeDB".to_owned())),
+                Some(Value::Uuid(author)),
+                Some(Value::Array(vec![Value::Uuid(reader)])),
+            ]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[insert_query(module = "users", table = "User")]
+    pub struct InsertUserWithFriends {
+        pub name: String,
+        pub friends: edgedb_query::Links<FindUser>,
+        #[field(link = "friends", column_name = "since")]
+        pub friends_since: i32,
+        #[field(link = "friends")]
+        pub rank: Option<i16>,
+    }
+
+    #[test]
+    fn insert_user_with_link_properties_test() {
+        let friend = Uuid::new_v4();
+
+        let query = InsertUserWithFriends {
+            name: "Joe".to_owned(),
+            friends: edgedb_query::Links::new(vec![friend]),
+            friends_since: 2020,
+            rank: None,
+        }.to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "insert users::User {name := (select <str>$name), friends := (select users::User { @since := (select <int32>$friends_since) } filter .id in array_unpack(<array<uuid>>$friends)), }"
+        );
+
+        if let Some(Value::Object { fields, .. }) = query.args {
+            assert_eq!(fields, vec![
+                Some(Value::Str("Joe".to_owned())),
+                Some(Value::Array(vec![Value::Uuid(friend)])),
+                Some(Value::Int32(2020)),
+            ]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[insert_query(module = "users", table = "User")]
+    pub struct InsertUserWithWallets {
+        pub name: String,
+        #[nested_query]
+        pub main_wallet: Option<Wallet>,
+        #[nested_query]
+        pub wallets: Vec<Wallet>,
+    }
+
+    #[test]
+    fn insert_user_with_many_wallets_test() {
+        let insert_user = InsertUserWithWallets {
+            name: "Joe".to_owned(),
+            main_wallet: None,
+            wallets: vec![Wallet { money: 10 }, Wallet { money: 20 }],
+        };
+
+        let query = insert_user.to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "insert users::User {name := (select <str>$name), wallets := (for item in json_array_unpack(<json>$wallets) union (insert users::Wallet {money := (<int16>item['money']), })), }"
+        );
+
+        if let Some(Value::Object { shape, fields }) = query.args {
+            crate::test_utils::check_shape(&shape, vec!["name", "wallets"]);
+
+            assert_eq!(fields, vec![
+                Some(Value::Str("Joe".to_owned())),
+                Some(Value::Json(r#"[{"money":10},{"money":20}]"#.to_owned())),
+            ]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn insert_user_with_optional_wallet_test() {
+        let insert_user = InsertUserWithWallets {
+            name: "Joe".to_owned(),
+            main_wallet: Some(Wallet { money: 10 }),
+            wallets: vec![],
+        };
+
+        let query = insert_user.to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "insert users::User {name := (select <str>$name), main_wallet := (insert users::Wallet {money := (select <int16>$main_wallet_money), }), wallets := (for item in json_array_unpack(<json>$wallets) union (insert users::Wallet {money := (<int16>item['money']), })), }"
+        );
+
+        if let Some(Value::Object { shape, fields }) = query.args {
+            crate::test_utils::check_shape(&shape, vec!["name", "main_wallet_money", "wallets"]);
+
+            assert_eq!(fields, vec![
+                Some(Value::Str("Joe".to_owned())),
+                Some(Value::Int16(10)),
+                Some(Value::Json("[]".to_owned())),
+            ]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[insert_query(module = "users", table = "Pet")]
+    pub struct InsertPet {
+        pub name: String,
+    }
+
+    #[insert_query(module = "users", table = "User")]
+    pub struct InsertUserWithPets {
+        pub name: String,
+        #[nested_query]
+        pub cat: Option<InsertPet>,
+        #[nested_query]
+        pub dog: Option<InsertPet>,
+    }
+
+    #[test]
+    fn insert_user_with_optional_pets_test() {
+        let insert_user = InsertUserWithPets {
+            name: "Joe".to_owned(),
+            cat: Some(InsertPet { name: "Tom".to_owned() }),
+            dog: Some(InsertPet { name: "Rex".to_owned() }),
+        };
+
+        let query = insert_user.to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "insert users::User {name := (select <str>$name), cat := (insert users::Pet {name := (select <str>$cat_name), }), dog := (insert users::Pet {name := (select <str>$dog_name), }), }"
+        );
+
+        if let Some(Value::Object { shape, fields }) = query.args {
+            crate::test_utils::check_shape(&shape, vec!["name", "cat_name", "dog_name"]);
+
+            assert_eq!(fields, vec![
+                Some(Value::Str("Joe".to_owned())),
+                Some(Value::Str("Tom".to_owned())),
+                Some(Value::Str("Rex".to_owned())),
+            ]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[insert_query(module = "blog", table = "Post")]
+    pub struct InsertPostWithExpressions {
+        pub title: String,
+        #[expr("str_lower(<str>$title_slug)")]
+        #[field(column_name = "slug", param = "title_slug")]
+        pub slug: String,
+        #[expr("datetime_current()")]
+        pub created_at: (),
+        #[expr("<str>$subtitle ++ '!'")]
+        pub subtitle: Option<String>,
+    }
+
+    #[test]
+    fn insert_with_expressions_test() {
+        let insert_post = InsertPostWithExpressions {
+            title: "Hello World".to_owned(),
+            slug: "Hello World".to_owned(),
+            created_at: (),
+            subtitle: None,
+        };
+
+        let query = insert_post.to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "insert blog::Post {title := (select <str>$title), slug := (str_lower(<str>$title_slug)), created_at := (datetime_current()), }"
+        );
+
+        if let Some(Value::Object { shape, fields }) = query.args {
+            crate::test_utils::check_shape(&shape, vec!["title", "title_slug"]);
+
+            assert_eq!(fields, vec![
+                Some(Value::Str("Hello World".to_owned())),
+                Some(Value::Str("Hello World".to_owned())),
+            ]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn insert_many_with_expressions_test() {
+        let query = edgedb_query::InsertMany::<InsertPostWithExpressions>::new(vec![]).to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "for item in json_array_unpack(<json>$items) union (insert blog::Post {title := (<str>item['title']), slug := (str_lower(<str>(<str>item['title_slug']))), created_at := (datetime_current()), subtitle := (<str>(<str>json_get(item, 'subtitle')) ++ '!'), })"
+        );
+    }
+
+    #[insert_query(module = "blog", table = "Tag")]
+    pub struct InsertTag {
+        #[expr("<str>$label ++ ' ($label)'")]
+        pub label: String,
+    }
+
+    #[test]
+    fn insert_many_expression_with_literal_test() {
+        let query = edgedb_query::InsertMany::new(vec![InsertTag { label: "rust".to_owned() }]).to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "for item in json_array_unpack(<json>$items) union (insert blog::Tag {label := (<str>(<str>item['label']) ++ ' ($label)'), })"
+        );
+
+        if let Some(Value::Object { shape, fields }) = query.args {
+            crate::test_utils::check_shape(&shape, vec!["items"]);
+            assert_eq!(fields, vec![Some(Value::Json(r#"[{"label":"rust"}]"#.to_owned()))]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[insert_query(module = "bank", table = "Account")]
+    pub struct InsertAccount {
+        pub owner: uuid::Uuid,
+        pub balance: edgedb_protocol::model::BigInt,
+        pub rate: Option<edgedb_protocol::model::Decimal>,
+    }
+
+    #[test]
+    fn insert_big_numbers_test() {
+        let insert_account = InsertAccount {
+            owner: Uuid::new_v4(),
+            balance: edgedb_protocol::model::BigInt::from(1_000_000_i64),
+            rate: None,
+        };
+
+        let query = insert_account.to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "insert bank::Account {owner := (select <uuid>$owner), balance := (select <bigint>$balance), }"
+        );
+
+        if let Some(Value::Object { shape, fields }) = query.args {
+            crate::test_utils::check_shape(&shape, vec!["owner", "balance"]);
+
+            assert_eq!(fields[1], Some(Value::BigInt(edgedb_protocol::model::BigInt::from(1_000_000_i64))));
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[insert_query(module = "files", table = "Attachment")]
+    pub struct InsertAttachment {
+        pub content: Vec<u8>,
+        pub thumbnail: Option<edgedb_query::Bytes>,
+        pub signature: bytes::Bytes,
+    }
+
+    #[test]
+    fn insert_bytes_test() {
+        let insert_attachment = InsertAttachment {
+            content: vec![0xca, 0xfe],
+            thumbnail: Some(edgedb_query::Bytes::from(vec![0x01])),
+            signature: bytes::Bytes::from_static(&[0x02]),
+        };
+
+        let query = insert_attachment.to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "insert files::Attachment {content := (select <bytes>$content), thumbnail := (select <bytes>$thumbnail), signature := (select <bytes>$signature), }"
+        );
+
+        if let Some(Value::Object { shape, fields }) = query.args {
+            crate::test_utils::check_shape(&shape, vec!["content", "thumbnail", "signature"]);
+
+            assert_eq!(fields, vec![
+                Some(Value::Bytes(vec![0xca, 0xfe])),
+                Some(Value::Bytes(vec![0x01])),
+                Some(Value::Bytes(vec![0x02])),
+            ]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[insert_query(module = "stats", table = "Counter")]
+    pub struct InsertCounter {
+        pub small: u16,
+        pub medium: u32,
+        pub large: u64,
+    }
+
+    #[test]
+    fn insert_unsigned_test() {
+        let insert_counter = InsertCounter {
+            small: u16::MAX,
+            medium: u32::MAX,
+            large: 42,
+        };
+
+        let query = insert_counter.to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "insert stats::Counter {small := (select <int32>$small), medium := (select <int64>$medium), large := (select <int64>$large), }"
+        );
+
+        if let Some(Value::Object { fields, .. }) = query.args {
+            assert_eq!(fields, vec![
+                Some(Value::Int32(65_535)),
+                Some(Value::Int64(4_294_967_295)),
+                Some(Value::Int64(42)),
+            ]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn insert_unsigned_overflow_test() {
+        let insert_counter = InsertCounter {
+            small: 0,
+            medium: 0,
+            large: u64::MAX,
+        };
+
+        let result = insert_counter.try_to_edge_query();
+
+        assert_eq!(result.err(), Some(edgedb_query::ValueOverflow::new(u64::MAX, "int64")));
+    }
+
+    #[select_query(module = "stats", table = "Counter")]
+    pub struct FindCounter {
+        #[filter(operator = "=")]
+        pub large: u64,
+    }
+
+    #[insert_query(module = "stats", table = "Counter")]
+    pub struct InsertUniqueCounter {
+        pub large: u64,
+        #[unless_conflict(on = "large")]
+        pub find_counter: UnlessConflictElse<FindCounter>,
+    }
+
+    #[test]
+    fn insert_conflict_else_overflow_test() {
+        let insert_counter = InsertUniqueCounter {
+            large: 42,
+            find_counter: UnlessConflictElse {
+                else_query: FindCounter { large: u64::MAX },
+            },
+        };
+
+        let result = insert_counter.try_to_edge_query();
+
+        assert_eq!(result.err(), Some(edgedb_query::ValueOverflow::new(u64::MAX, "int64")));
+    }
+
+    #[test]
+    fn insert_many_unsigned_overflow_test() {
+        let counters = edgedb_query::InsertMany::new(vec![
+            InsertCounter { small: 0, medium: 0, large: 42 },
+            InsertCounter { small: 0, medium: 0, large: u64::MAX },
+        ]);
+
+        let result = counters.try_to_edge_query();
+
+        assert_eq!(result.err(), Some(edgedb_query::ValueOverflow::new(u64::MAX, "int64")));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[insert_query(module = "calendar", table = "Event")]
+    pub struct InsertEvent {
+        pub starts_at: chrono::DateTime<chrono::Utc>,
+        pub day: chrono::NaiveDate,
+        pub length: chrono::Duration,
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn insert_chrono_test() {
+        let insert_event = InsertEvent {
+            starts_at: chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2023, 5, 17, 10, 0, 0).unwrap(),
+            day: chrono::NaiveDate::from_ymd_opt(2023, 5, 17).unwrap(),
+            length: chrono::Duration::minutes(90),
+        };
+
+        let query = insert_event.to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "insert calendar::Event {starts_at := (select <datetime>$starts_at), day := (select <cal::local_date>$day), length := (select <duration>$length), }"
+        );
+
+        if let Some(Value::Object { shape, fields }) = query.args {
+            crate::test_utils::check_shape(&shape, vec!["starts_at", "day", "length"]);
+
+            assert!(matches!(fields[0], Some(Value::Datetime(_))));
+            assert!(matches!(fields[1], Some(Value::LocalDate(_))));
+            assert_eq!(fields[2], Some(Value::Duration(edgedb_protocol::model::Duration::from_micros(90 * 60 * 1_000_000))));
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[cfg(feature = "time")]
+    #[insert_query(module = "calendar", table = "Meeting")]
+    pub struct InsertMeeting {
+        pub starts_at: time::OffsetDateTime,
+        pub day: time::Date,
+        pub at: time::Time,
+        pub length: time::Duration,
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn insert_time_test() {
+        let insert_meeting = InsertMeeting {
+            starts_at: time::OffsetDateTime::UNIX_EPOCH,
+            day: time::Date::from_calendar_date(2023, time::Month::May, 17).unwrap(),
+            at: time::Time::MIDNIGHT,
+            length: time::Duration::minutes(90),
+        };
+
+        let query = insert_meeting.to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "insert calendar::Meeting {starts_at := (select <datetime>$starts_at), day := (select <cal::local_date>$day), at := (select <cal::local_time>$at), length := (select <duration>$length), }"
+        );
+
+        if let Some(Value::Object { shape, fields }) = query.args {
+            crate::test_utils::check_shape(&shape, vec!["starts_at", "day", "at", "length"]);
+
+            assert!(matches!(fields[0], Some(Value::Datetime(_))));
+            assert!(matches!(fields[1], Some(Value::LocalDate(_))));
+            assert!(matches!(fields[2], Some(Value::LocalTime(_))));
+            assert_eq!(fields[3], Some(Value::Duration(edgedb_protocol::model::Duration::from_micros(90 * 60 * 1_000_000))));
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[insert_query(module = "hotel", table = "Booking")]
+    pub struct InsertBooking {
+        pub guest: String,
+        pub stay: edgedb_query::Range<i64>,
+    }
+
+    #[test]
+    fn insert_range_test() {
+        let insert_booking = InsertBooking {
+            guest: "Joe".to_owned(),
+            stay: (10..20).into(),
+        };
+
+        let query = insert_booking.to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "insert hotel::Booking {guest := (select <str>$guest), stay := (select <range<int64>>$stay), }"
+        );
+
+        if let Some(Value::Object { shape, fields }) = query.args {
+            crate::test_utils::check_shape(&shape, vec!["guest", "stay"]);
+
+            assert!(matches!(fields[1], Some(Value::Range(_))));
+        } else {
+            unreachable!()
+        }
+    }
 }
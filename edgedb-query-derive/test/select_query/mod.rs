@@ -3,8 +3,12 @@ mod select {
     use edgedb_protocol::value::Value;
     use edgedb_query_derive::{edgedb_filters, query_result, select_query};
     use edgedb_query::models::edge_query::{ToEdgeQuery, EdgeQuery};
+    use edgedb_query::ToEdgeValue;
     use edgedb_query::queries::select::{OrderDir, OrderOptions, SelectOptions};
     use uuid::Uuid;
+    use edgedb_protocol::codec::{STD_INT16, STD_INT64, STD_STR};
+    use crate::test_utils::{decode_result, encode_array, encode_elements};
+    use edgedb_query::test_support::TypeDescBuilder;
 
 
     #[query_result]
@@ -313,5 +317,220 @@ mod select {
         }
     }
 
+    #[query_result(free_object = true)]
+    pub struct UsersStats {
+        #[field(expr = "count(users::User)")]
+        pub total: i64,
+        #[field(expr = "count((select users::User filter .age >= 18))")]
+        pub adults: i64,
+    }
+
+    #[select_query(result = "UsersStats")]
+    pub struct GetUsersStats {}
+
+    #[test]
+    pub fn free_object_select_test() {
+
+        let q = GetUsersStats {};
+
+        let edge_query : EdgeQuery = q.to_edge_query();
+
+        let expected = "select {total := (count(users::User)),adults := (count((select users::User filter .age >= 18)))}";
+
+        assert_eq!(edge_query.query, expected);
+    }
+
+    #[query_result(free_object = true)]
+    pub struct UsersNames {
+        #[field(expr = "count(users::User)")]
+        pub total: i64,
+        #[field(expr = "array_agg(users::User.name)")]
+        pub names: Vec<String>,
+    }
+
+    #[test]
+    pub fn decode_free_object_test() {
+        let mut builder = TypeDescBuilder::default();
+        let int64 = builder.base_scalar(STD_INT64);
+        let str = builder.base_scalar(STD_STR);
+        let names = builder.array(str);
+        builder.object_shape(false, &[("total", int64), ("names", names)]);
+        let desc = builder.build();
+
+        let buf = encode_elements(vec![
+            Some(2_i64.to_be_bytes().to_vec()),
+            Some(encode_array(vec![b"Joe".to_vec(), b"John".to_vec()])),
+        ]);
+
+        let result: UsersNames = decode_result(&desc, &buf);
+
+        assert_eq!(result.total, 2);
+        assert_eq!(result.names, vec!["Joe".to_owned(), "John".to_owned()]);
+    }
+
+    #[test]
+    pub fn decode_free_object_with_extra_element_test() {
+        use edgedb_protocol::queryable::{Decoder, Queryable};
+
+        let mut builder = TypeDescBuilder::default();
+        let int64 = builder.base_scalar(STD_INT64);
+        let str = builder.base_scalar(STD_STR);
+        let names = builder.array(str);
+        builder.object_shape(false, &[("count", int64), ("total", int64), ("names", names)]);
+        let desc = builder.build();
+
+        let check = UsersNames::check_descriptor(&desc.as_queryable_context(), desc.root_pos().unwrap());
+        assert!(check.is_err());
+
+        let buf = encode_elements(vec![
+            Some(1_i64.to_be_bytes().to_vec()),
+            Some(2_i64.to_be_bytes().to_vec()),
+            Some(encode_array(vec![b"Joe".to_vec()])),
+        ]);
+
+        assert!(UsersNames::decode(&Decoder::default(), &buf).is_err());
+    }
+
+    #[test]
+    pub fn decode_scalar_result_test() {
+        let mut builder = TypeDescBuilder::default();
+        builder.base_scalar(STD_INT64);
+        let desc = builder.build();
+
+        let count: i64 = decode_result(&desc, &42_i64.to_be_bytes());
+
+        assert_eq!(count, 42);
+    }
+
+    #[test]
+    pub fn decode_tuple_result_test() {
+        let mut builder = TypeDescBuilder::default();
+        let str = builder.base_scalar(STD_STR);
+        let int16 = builder.base_scalar(STD_INT16);
+        builder.tuple(&[str, int16]);
+        let desc = builder.build();
+
+        let buf = encode_elements(vec![Some(b"Joe".to_vec()), Some(35_i16.to_be_bytes().to_vec())]);
 
-}
\ No newline at end of file
+        let result: (String, i16) = decode_result(&desc, &buf);
+
+        assert_eq!(result, ("Joe".to_owned(), 35));
+    }
+
+    #[select_query(module = "users", table = "User", result = "UserResult")]
+    pub struct FindUsersWithShape {
+        #[shape]
+        pub shape: edgedb_query::DynamicShape<UserResult>,
+        #[filter(operator = "Is")]
+        pub name: String,
+    }
+
+    #[test]
+    pub fn select_with_dynamic_shape_test() {
+
+        let q = FindUsersWithShape {
+            shape: edgedb_query::DynamicShape::parse("age,name").unwrap(),
+            name: "Joe".to_string(),
+        };
+
+        let edge_query : EdgeQuery = q.to_edge_query();
+
+        let expected = "select users::User {name,age} filter users::User.name = (select <str>$name)";
+
+        assert_eq!(edge_query.query, expected);
+    }
+
+    #[test]
+    pub fn dynamic_shape_unknown_field_test() {
+        let shape = edgedb_query::DynamicShape::<UserResult>::parse("name,password");
+
+        assert!(shape.is_err());
+    }
+
+    #[test]
+    pub fn paged_select_test() {
+
+        let q = edgedb_query::Paged(FindMajorUsersWithOptions {
+            options: SelectOptions {
+                order_options: Some(OrderOptions {
+                    order_by: "name".to_string(),
+                    order_direction: Some(OrderDir::Desc)
+                }),
+                page_options: Some(edgedb_query::PageOptions {
+                    limit: 10,
+                    offset: Some(20)
+                })
+            },
+            age: 18
+        });
+
+        let edge_query : EdgeQuery = q.to_edge_query();
+
+        let expected = "with filtered := (select users::User filter users::User.age >= (select <int16>$age)) select {total := count(filtered),items := (select filtered {id,name,age} order by .name desc limit 10 offset 20)}";
+
+        assert_eq!(edge_query.query, expected);
+
+        if let Some(Value::Object { shape, fields }) = edge_query.args {
+            crate::test_utils::check_shape(&shape, vec!["age"]);
+            assert_eq!(fields, vec![
+                Some(Value::Int16(18))
+            ])
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[query_result]
+    pub struct BookingResult {
+        pub id: Uuid,
+        pub stay: edgedb_query::Range<i64>,
+    }
+
+    #[select_query(module = "hotel", table = "Booking", result = "BookingResult")]
+    pub struct FindOverlappingBookings {
+        #[filter(operator = "Overlaps")]
+        pub stay: edgedb_query::Range<i64>,
+    }
+
+    #[test]
+    pub fn filter_overlaps_test() {
+        let q = FindOverlappingBookings {
+            stay: (10..20).into(),
+        };
+
+        let edge_query : EdgeQuery = q.to_edge_query();
+
+        let expected_query = "select hotel::Booking {id,stay} filter overlaps(hotel::Booking.stay, (select <range<int64>>$stay))";
+
+        assert_eq!(edge_query.query, expected_query);
+
+        if let Some(Value::Object { shape, fields }) = edge_query.args {
+            crate::test_utils::check_shape(&shape, vec!["stay"]);
+            assert_eq!(fields, vec![
+                Some(edgedb_query::Range::<i64>::from(10..20).to_edge_value())
+            ])
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[select_query(module = "hotel", table = "Booking", result = "BookingResult")]
+    pub struct FindBookingsByDay {
+        #[field(column_name = "stay")]
+        #[filter(operator = "ContainsValue")]
+        pub day: i64,
+    }
+
+    #[test]
+    pub fn filter_contains_value_test() {
+        let q = FindBookingsByDay {
+            day: 12,
+        };
+
+        let edge_query : EdgeQuery = q.to_edge_query();
+
+        let expected_query = "select hotel::Booking {id,stay} filter contains(hotel::Booking.stay, (select <int64>$day))";
+
+        assert_eq!(edge_query.query, expected_query);
+    }
+}
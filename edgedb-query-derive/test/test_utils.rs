@@ -1,4 +1,6 @@
 use edgedb_protocol::codec::ObjectShape;
+use edgedb_protocol::descriptors::OutputTypedesc;
+use edgedb_protocol::queryable::{Decoder, Queryable};
 
 pub fn check_shape(shape: &ObjectShape, expected_elements: Vec<&str>) {
     let elements = &shape.elements;
@@ -8,4 +10,48 @@ pub fn check_shape(shape: &ObjectShape, expected_elements: Vec<&str>) {
         .collect::<Vec<String>>();
 
     assert_eq!(vars, expected_elements);
-}
\ No newline at end of file
+}
+
+/// Encode an object or a tuple value, a None element being a null one
+pub fn encode_elements(elements: Vec<Option<Vec<u8>>>) -> Vec<u8> {
+    let mut buf = (elements.len() as u32).to_be_bytes().to_vec();
+
+    for element in elements {
+        buf.extend(0_i32.to_be_bytes());
+        match element {
+            Some(data) => {
+                buf.extend((data.len() as i32).to_be_bytes());
+                buf.extend(data);
+            }
+            None => buf.extend((-1_i32).to_be_bytes()),
+        }
+    }
+
+    buf
+}
+
+/// Encode a one dimension array or set value
+pub fn encode_array(elements: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut buf = vec![];
+
+    buf.extend(1_u32.to_be_bytes());
+    buf.extend(0_u32.to_be_bytes());
+    buf.extend(0_u32.to_be_bytes());
+    buf.extend((elements.len() as u32).to_be_bytes());
+    buf.extend(1_u32.to_be_bytes());
+
+    for data in elements {
+        buf.extend((data.len() as i32).to_be_bytes());
+        buf.extend(data);
+    }
+
+    buf
+}
+
+/// Check the result descriptor then decode the result value, as a client does
+pub fn decode_result<T: Queryable>(desc: &OutputTypedesc, buf: &[u8]) -> T {
+    T::check_descriptor(&desc.as_queryable_context(), desc.root_pos().expect("no root descriptor"))
+        .expect("descriptor mismatch");
+
+    T::decode(&Decoder::default(), buf).expect("decode error")
+}
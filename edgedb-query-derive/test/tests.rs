@@ -1,4 +1,5 @@
 mod insert_query;
+mod upsert_query;
 mod select_query;
 mod test_utils;
 mod edge_result;
@@ -7,4 +8,6 @@ mod delete_query;
 mod edge_set;
 mod update_query;
 mod from_file_query;
-mod query;
\ No newline at end of file
+mod query;
+mod edge_tuple;
+mod edge_scalar;
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod update {
     use edgedb_protocol::value::Value;
-    use edgedb_query_derive::{edgedb_filters, edgedb_sets, query, query_result, update_query};
+    use edgedb_query_derive::{edgedb_filters, edgedb_sets, query, query_result, select_query, update_query};
     use edgedb_query::models::edge_query::ToEdgeQuery;
     use edgedb_query::queries::set::Sets;
     use uuid::Uuid;
@@ -172,4 +172,330 @@ mod update {
             ]);
         }
     }
-}
\ No newline at end of file
+
+    #[select_query(module = "users", table = "User")]
+    pub struct FindFriend {
+        #[filter(operator = "=")]
+        pub id: Uuid,
+    }
+
+    #[update_query(module = "users", table = "User")]
+    pub struct UpdateUserLinks {
+        pub best_friend: edgedb_query::Link<FindFriend>,
+        pub friends: edgedb_query::Links<FindFriend>,
+        #[filter(operator = "=")]
+        pub id: Uuid,
+    }
+
+    #[test]
+    pub fn test_links() {
+        let q = UpdateUserLinks {
+            best_friend: edgedb_query::Link::new(Uuid::new_v4()),
+            friends: edgedb_query::Links::new(vec![Uuid::new_v4(), Uuid::new_v4()]),
+            id: Uuid::new_v4(),
+        };
+
+        let eq = q.to_edge_query();
+
+        let expected_query = r#"
+            update users::User
+            filter users::User.id = (select <uuid>$id)
+            set {
+                best_friend := ((select users::User filter .id = <uuid>$best_friend)),
+                friends := ((select users::User filter .id in array_unpack(<array<uuid>>$friends)))
+            }
+        "#.to_owned().replace('\n', "");
+
+        assert_eq!(eq.query.replace(' ', ""), expected_query.replace(' ', ""));
+
+        if let Some(Value::Object { shape, .. }) = eq.args {
+            check_shape(&shape, vec!["id", "best_friend", "friends"]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[update_query(module = "users", table = "User")]
+    pub struct UpdateBestFriend {
+        pub best_friend: edgedb_query::Link<FindFriend>,
+        #[field(link = "best_friend")]
+        pub rank: i16,
+        #[filter(operator = "=")]
+        pub id: Uuid,
+    }
+
+    #[test]
+    pub fn test_link_properties() {
+        let q = UpdateBestFriend {
+            best_friend: edgedb_query::Link::new(Uuid::new_v4()),
+            rank: 1,
+            id: Uuid::new_v4(),
+        };
+
+        let eq = q.to_edge_query();
+
+        let expected_query = r#"
+            update users::User
+            filter users::User.id = (select <uuid>$id)
+            set {
+                best_friend := ((select users::User { @rank := (select <int16>$rank) } filter .id = <uuid>$best_friend))
+            }
+        "#.to_owned().replace('\n', "");
+
+        assert_eq!(eq.query.replace(' ', ""), expected_query.replace(' ', ""));
+
+        if let Some(Value::Object { shape, .. }) = eq.args {
+            check_shape(&shape, vec!["id", "best_friend", "rank"]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[update_query(module = "users", table = "User")]
+    pub struct PatchUser {
+        #[set(if_none = "skip")]
+        pub name: Option<String>,
+        #[set(if_none = "clear")]
+        pub nickname: Option<String>,
+        pub age: Option<i16>,
+        #[filter(operator = "=")]
+        pub id: Uuid,
+    }
+
+    #[test]
+    pub fn test_none_fields() {
+        let q = PatchUser {
+            name: None,
+            nickname: None,
+            age: Some(30),
+            id: Uuid::new_v4(),
+        };
+
+        let eq = q.to_edge_query();
+
+        assert_eq!(
+            eq.query.replace(' ', ""),
+            "update users::User filter users::User.id = (select <uuid>$id) set { nickname := {}, age := (select <int16>$age) }".replace(' ', "")
+        );
+
+        if let Some(Value::Object { shape, .. }) = eq.args {
+            check_shape(&shape, vec!["id", "age"]);
+        } else {
+            unreachable!()
+        }
+
+        let q = PatchUser {
+            name: Some("Joe".to_owned()),
+            nickname: Some("Jo".to_owned()),
+            age: None,
+            id: Uuid::new_v4(),
+        };
+
+        assert_eq!(
+            q.to_edge_query().query.replace(' ', ""),
+            "update users::User filter users::User.id = (select <uuid>$id) set { name := (select <str>$name), nickname := (select <str>$nickname) }".replace(' ', "")
+        );
+    }
+
+    #[update_query(module = "users", table = "User")]
+    pub struct PatchUserNames {
+        pub name: Option<String>,
+        pub nickname: Option<String>,
+        #[filter(operator = "=")]
+        pub id: Uuid,
+    }
+
+    #[test]
+    pub fn test_all_none_fields() {
+        let q = PatchUserNames {
+            name: None,
+            nickname: None,
+            id: Uuid::new_v4(),
+        };
+
+        let eq = q.to_edge_query();
+
+        assert_eq!(
+            eq.query.replace(' ', ""),
+            "select users::User filter users::User.id = (select <uuid>$id)".replace(' ', "")
+        );
+
+        if let Some(Value::Object { shape, .. }) = eq.args {
+            check_shape(&shape, vec!["id"]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[update_query(module = "users", table = "User")]
+    pub struct UpdateFriends {
+        #[set(option = "push")]
+        pub new_friends: edgedb_query::Links<FindFriend>,
+        #[field(link = "new_friends")]
+        pub since: i32,
+        #[set(option = "remove")]
+        #[field(column_name = "friends")]
+        pub old_friends: edgedb_query::Links<FindFriend>,
+        #[filter(operator = "=")]
+        pub id: Uuid,
+    }
+
+    #[test]
+    pub fn test_add_and_remove_links() {
+        let q = UpdateFriends {
+            new_friends: edgedb_query::Links::new(vec![Uuid::new_v4()]),
+            since: 2023,
+            old_friends: edgedb_query::Links::new(vec![Uuid::new_v4()]),
+            id: Uuid::new_v4(),
+        };
+
+        let eq = q.to_edge_query();
+
+        let expected_query = r#"
+            update users::User
+            filter users::User.id = (select <uuid>$id)
+            set {
+                new_friends += ((select users::User { @since := (select <int32>$since) } filter .id in array_unpack(<array<uuid>>$new_friends))),
+                friends -= ((select users::User filter .id in array_unpack(<array<uuid>>$old_friends)))
+            }
+        "#.to_owned().replace('\n', "");
+
+        assert_eq!(eq.query.replace(' ', ""), expected_query.replace(' ', ""));
+
+        if let Some(Value::Object { shape, .. }) = eq.args {
+            check_shape(&shape, vec!["id", "new_friends", "since", "old_friends"]);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[update_query(module = "users", table = "User", version = "version")]
+    pub struct UpdateUserNameVersioned {
+        pub name: String,
+        #[filter(operator = "=")]
+        pub id: Uuid,
+    }
+
+    #[test]
+    pub fn test_versioned() {
+        use edgedb_query::queries::version::VersionedUpdate;
+
+        let q = UpdateUserNameVersioned {
+            name: "Joe".to_owned(),
+            id: Uuid::new_v4(),
+            expected_version: 2,
+        };
+
+        let eq = q.to_edge_query();
+
+        let expected_query = r#"
+            update users::User
+            filter (users::User.id = (select <uuid>$id)) and users::User.version = (select <int64>$expected_version)
+            set {
+                name := (select <str>$name),
+                version := .version + 1
+            }
+        "#.to_owned().replace('\n', "");
+
+        assert_eq!(eq.query.replace(' ', ""), expected_query.replace(' ', ""));
+
+        if let Some(Value::Object { shape, fields }) = eq.args {
+            check_shape(&shape, vec!["id", "name", "expected_version"]);
+            assert_eq!(fields.last(), Some(&Some(Value::Int64(2))));
+        } else {
+            unreachable!()
+        }
+
+        assert!(q.check_updated_single::<()>(None).is_err());
+    }
+
+    #[update_query(module = "users", table = "User", version = "version")]
+    pub struct RenameUsersVersioned {
+        pub name: String,
+        #[filter(operator = "=")]
+        #[field(column_name = "name", param = "old_name")]
+        pub old_name: String,
+        #[or_filter(operator = "=")]
+        pub nickname: String,
+    }
+
+    #[test]
+    pub fn test_versioned_or_filters() {
+        let q = RenameUsersVersioned {
+            name: "Joe".to_owned(),
+            old_name: "John".to_owned(),
+            nickname: "Jo".to_owned(),
+            expected_version: 2,
+        };
+
+        let expected_query = r#"
+            update users::User
+            filter (users::User.name = (select <str>$old_name) or users::User.nickname = (select <str>$nickname))
+                and users::User.version = (select <int64>$expected_version)
+            set {
+                name := (select <str>$name),
+                version := .version + 1
+            }
+        "#.to_owned().replace('\n', "");
+
+        assert_eq!(q.to_edge_query().query.replace(' ', ""), expected_query.replace(' ', ""));
+    }
+
+    #[update_query(module = "users", table = "User", version = "version")]
+    pub struct RenameAllUsersVersioned {
+        pub name: String,
+    }
+
+    #[test]
+    pub fn test_versioned_without_filter() {
+        let q = RenameAllUsersVersioned {
+            name: "Joe".to_owned(),
+            expected_version: 2,
+        };
+
+        assert_eq!(
+            q.to_edge_query().query.replace(' ', ""),
+            "update users::User filter users::User.version = (select <int64>$expected_version) set { name := (select <str>$name), version := .version + 1 }".replace(' ', "")
+        );
+    }
+
+    #[update_query(module = "users", table = "User")]
+    pub struct UpdateUserScore {
+        #[expr(".score + <int64>$delta")]
+        #[field(column_name = "score")]
+        pub delta: i64,
+        #[expr("datetime_current()")]
+        pub updated_at: (),
+        #[filter(operator = "=")]
+        pub id: Uuid,
+    }
+
+    #[test]
+    pub fn test_update_with_expressions() {
+        let q = UpdateUserScore {
+            delta: 5,
+            updated_at: (),
+            id: Uuid::new_v4(),
+        };
+
+        let eq = q.to_edge_query();
+
+        let expected_query = r#"
+            update users::User
+            filter users::User.id = (select <uuid>$id)
+            set {
+                score := (.score + <int64>$delta),
+                updated_at := (datetime_current())
+            }
+        "#.to_owned().replace('\n', "");
+
+        assert_eq!(eq.query.replace(' ', ""), expected_query.replace(' ', ""));
+
+        if let Some(Value::Object { shape, fields }) = eq.args {
+            check_shape(&shape, vec!["id", "delta"]);
+            assert_eq!(fields[1], Some(Value::Int64(5)));
+        } else {
+            unreachable!()
+        }
+    }
+}
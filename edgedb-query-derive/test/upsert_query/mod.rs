@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod upsert {
+    use edgedb_protocol::value::Value;
+    use edgedb_query::{ToEdgeQuery, EdgeQuery};
+    use uuid::Uuid;
+    use edgedb_query_derive::{upsert_query, query_result};
+
+    #[query_result]
+    pub struct UserResult {
+        pub id: Uuid,
+        pub name: String,
+    }
+
+    #[upsert_query(module = "users", table = "User", on = "email", result = "UserResult")]
+    pub struct UpsertUser {
+        pub email: String,
+        pub name: String,
+        pub age: Option<i16>,
+    }
+
+    #[test]
+    fn upsert_all_non_key_fields_test() {
+        let upsert_user = UpsertUser {
+            email: "joe@mail.com".to_string(),
+            name: "Joe".to_string(),
+            age: Some(35),
+        };
+
+        let query: EdgeQuery = upsert_user.to_edge_query();
+
+        let expected = r#"
+            select (
+                insert users::User {
+                    email := (select <str>$email),
+                    name := (select <str>$name),
+                    age := (select <int16>$age),
+                } unless conflict on .email else (
+                    update users::User set {
+                        name := (select <str>$name),
+                        age := (select <int16>$age),
+                    }
+                )
+            ) {id,name}
+        "#
+        .to_owned()
+        .replace('\n', "");
+
+        assert_eq!(query.query.replace(' ', ""), expected.replace(' ', ""));
+
+        if let Some(Value::Object { shape, fields }) = query.args {
+            crate::test_utils::check_shape(&shape, vec!["email", "name", "age"]);
+            assert_eq!(fields, vec![
+                Some(Value::Str("joe@mail.com".to_string())),
+                Some(Value::Str("Joe".to_string())),
+                Some(Value::Int16(35)),
+            ])
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[upsert_query(module = "users", table = "User", on = "email, login")]
+    pub struct UpsertUserName {
+        pub email: String,
+        #[field(column_name = "login")]
+        pub username: String,
+        #[overwrite]
+        pub name: String,
+        pub created_at: String,
+    }
+
+    #[test]
+    fn upsert_overwritten_fields_test() {
+        let upsert_user = UpsertUserName {
+            email: "joe@mail.com".to_string(),
+            username: "joe".to_string(),
+            name: "Joe".to_string(),
+            created_at: "today".to_string(),
+        };
+
+        let query: EdgeQuery = upsert_user.to_edge_query();
+
+        assert_eq!(
+            query.query,
+            "insert users::User {email := (select <str>$email), login := (select <str>$username), name := (select <str>$name), created_at := (select <str>$created_at), } unless conflict on ( .email, .login ) else ( update users::User set { name := (select <str>$name), } ) "
+        );
+    }
+}
@@ -3,42 +3,125 @@
 
 pub mod queries;
 pub mod models;
+pub mod scalars;
+#[doc(hidden)]
+pub mod test_support;
 
 use std::fmt::{Display, Formatter};
 pub use models::edge_query::EdgeQuery;
 pub use models::edge_query::ToEdgeQuery;
 pub use models::query_result::BasicResult;
+pub use models::dynamic_shape::DynamicShape;
+pub use models::link::Link;
+pub use models::link::Links;
+pub use models::bytes::Bytes;
+#[cfg(feature = "rust_decimal")]
+pub use models::decimal::RustDecimal;
+pub use models::range::Range;
 pub use queries::filter::Filter;
 pub use queries::select::Options;
 pub use queries::select::SelectOptions;
 pub use queries::select::OrderDir;
 pub use queries::select::OrderOptions;
 pub use queries::select::PageOptions;
+pub use queries::paged::Paged;
+pub use queries::paged::Page;
+pub use queries::bulk::InsertMany;
+pub use queries::version::StaleVersion;
 
 use edgedb_protocol::model::Uuid;
 use edgedb_protocol::value::Value;
 use crate::QueryType::{Delete, Insert, Select, Update};
 
 macro_rules! _to_edgeql_and_to_edge_scalar_impls {
-    ($($ty: ty => { scalar: $scalar: expr }),* $(,)?) => {
+    ($($(#[$attr: meta])* $ty: ty => { scalar: $scalar: expr $(, vec_scalar: $vec_scalar: expr)? }),* $(,)?) => {
         $(
+            $(#[$attr])*
             impl ToEdgeQl for $ty {
                 fn to_edgeql(&self) -> EdgeQl {
                     EdgeQl::new(self.to_string(), false)
                 }
             }
 
+            _to_edge_scalar_impls!($(#[$attr])* $ty => { scalar: $scalar $(, vec_scalar: $vec_scalar)? });
+        )*
+    }
+}
+
+macro_rules! _to_edge_scalar_impls {
+    ($($(#[$attr: meta])* $ty: ty => { scalar: $scalar: expr $(, vec_scalar: $vec_scalar: expr)? }),* $(,)?) => {
+        $(
+            $(#[$attr])*
             impl ToEdgeScalar for $ty {
                 fn scalar() -> String {
                     $scalar.to_owned()
                 }
+
+                $(
+                fn vec_scalar() -> String {
+                    $vec_scalar.to_owned()
+                }
+                )?
             }
 
+            $(#[$attr])*
             impl ToEdgeShape for $ty {
                 fn shape() -> String {
                     String::default()
                 }
             }
+
+            $(#[$attr])*
+            impl EdgeResult for $ty {
+                fn returning_fields() -> Vec<&'static str> {
+                    vec![]
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! _tuple_impls {
+    ($(($($name: ident),+)),* $(,)?) => {
+        $(
+            impl<$($name: ToEdgeScalar),+> ToEdgeScalar for ($($name,)+) {
+                fn scalar() -> String {
+                    let elements: Vec<String> = vec![$(scalar_name::<$name>()),+];
+                    format!("<tuple<{}>>", elements.join(", "))
+                }
+            }
+
+            impl<$($name: ToEdgeValue),+> ToEdgeValue for ($($name,)+) {
+                /// ⚠️ panics when an element is out of its edgeDB type range, use __try_to_edge_value__ to handle the overflow
+                fn to_edge_value(&self) -> Value {
+                    self.try_to_edge_value().unwrap_or_else(|e| panic!("{e}"))
+                }
+
+                #[allow(non_snake_case)]
+                fn try_to_edge_value(&self) -> Result<Value, ValueOverflow> {
+                    let ($($name,)+) = self;
+                    Ok(Value::Tuple(vec![$($name.try_to_edge_value()?),+]))
+                }
+            }
+
+            // tuples have no edgeql literal : they are always passed as query parameters
+            impl<$($name),+> ToEdgeQl for ($($name,)+) {
+                fn to_edgeql(&self) -> EdgeQl {
+                    EdgeQl::default()
+                }
+            }
+
+            impl<$($name),+> ToEdgeShape for ($($name,)+) {
+                fn shape() -> String {
+                    String::default()
+                }
+            }
+
+            impl<$($name),+> EdgeResult for ($($name,)+) {
+                fn returning_fields() -> Vec<&'static str> {
+                    vec![]
+                }
+            }
         )*
     }
 }
@@ -98,6 +181,7 @@ impl ToString for EdgeQl {
             (true, QueryType::None) => String::default(),
             (true, _) => format!("{} {}", self.query_type, self.table_name),
             (false, QueryType::None) => self.content.clone(),
+            (false, _) if self.table_name.is_empty() => format!("{} {}", self.query_type, self.content),
             (false, _) => format!("{} {} {}", self.query_type, self.table_name, self.content),
         };
 
@@ -127,6 +211,27 @@ impl EdgeQl {
             has_result: self.has_result,
         }
     }
+
+    /// Prefix every query parameter with __prefix__ (e.g. __$name__ becomes __$wallet_name__)
+    pub fn prefixed_params(&self, prefix: &str) -> Self {
+        let mut content = String::with_capacity(self.content.len());
+        let mut chars = self.content.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            content.push(c);
+            if c == '$' && chars.peek().is_some_and(|n| n.is_alphabetic() || *n == '_') {
+                content.push_str(prefix);
+                content.push('_');
+            }
+        }
+
+        Self {
+            table_name: self.table_name.clone(),
+            query_type: self.query_type.clone(),
+            content,
+            has_result: self.has_result,
+        }
+    }
 }
 
 
@@ -138,6 +243,30 @@ pub trait ToEdgeQl {
 pub trait ToEdgeScalar {
     /// returns the cast expression corresponding to the self struct
     fn scalar() -> String;
+
+    /// returns the cast expression corresponding to a vec of self struct
+    fn vec_scalar() -> String {
+        format!("<array{}>", Self::scalar())
+    }
+}
+
+/// EdgedbScalar marks a custom scalar newtype (decorated #[edgedb_scalar]),
+/// whose cast expression is only known from its ToEdgeScalar implementation
+#[diagnostic::on_unimplemented(
+    message = "Unsupported type: {Self}",
+    note = "a custom scalar newtype must be decorated #[edgedb_scalar], any other type needs a #[field(scalar = \"...\")]"
+)]
+pub trait EdgedbScalar: ToEdgeScalar {}
+
+/// returns the scalar of __T__ without its cast brackets (e.g. __int64__ for __i64__),
+/// as it appears within a composite type (e.g. __tuple<int64, str>__)
+pub fn scalar_name<T: ToEdgeScalar>() -> String {
+    let scalar = T::scalar();
+
+    match scalar.strip_prefix('<').and_then(|s| s.strip_suffix('>')) {
+        Some(name) => name.to_owned(),
+        None => scalar,
+    }
 }
 
 pub trait ToEdgeShape {
@@ -147,8 +276,53 @@ pub trait ToEdgeShape {
 pub trait ToEdgeValue {
     /// Transform a struct data into a edgedb_protocol::value::Value
     fn to_edge_value(&self) -> Value;
+
+    /// Transform a struct data into a edgedb_protocol::value::Value,
+    /// failing when the data overflows its edgeDB type
+    fn try_to_edge_value(&self) -> Result<Value, ValueOverflow> {
+        Ok(self.to_edge_value())
+    }
+
+    /// Transform a slice of struct data into a edgedb_protocol::value::Value
+    fn vec_edge_value(items: &[Self]) -> Value where Self: Sized {
+        Value::Array(
+            items.iter()
+                .map(|t| t.to_edge_value())
+                .collect::<Vec<Value>>(),
+        )
+    }
+
+    /// Transform a slice of struct data into a edgedb_protocol::value::Value,
+    /// failing when an item overflows its edgeDB type
+    fn try_vec_edge_value(items: &[Self]) -> Result<Value, ValueOverflow> where Self: Sized {
+        items.iter()
+            .map(|t| t.try_to_edge_value())
+            .collect::<Result<Vec<Value>, ValueOverflow>>()
+            .map(Value::Array)
+    }
+}
+
+/// ValueOverflow error : a value does not fit in the edgeDB type it is encoded to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueOverflow {
+    pub value: String,
+    pub scalar: String,
+}
+
+impl ValueOverflow {
+    pub fn new(value: impl ToString, scalar: impl Into<String>) -> Self {
+        Self { value: value.to_string(), scalar: scalar.into() }
+    }
 }
 
+impl Display for ValueOverflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Value {} overflows the edgeDB {} type", self.value, self.scalar)
+    }
+}
+
+impl std::error::Error for ValueOverflow {}
+
 pub trait EdgeResult {
     fn returning_fields() -> Vec<&'static str>;
 }
@@ -156,11 +330,11 @@ pub trait EdgeResult {
 _to_edgeql_and_to_edge_scalar_impls!(
     String => { scalar: "<str>" },
     i8 => { scalar: "<int16>" },
-    u8 => { scalar: "<int16>" },
+    u8 => { scalar: "<int16>", vec_scalar: "<bytes>" },
     i16 => { scalar: "<int16>" },
-    u16 => { scalar: "<int16>" },
+    u16 => { scalar: "<int32>" },
     i32 => { scalar: "<int32>" },
-    u32 => { scalar: "<int32>" },
+    u32 => { scalar: "<int64>" },
     i64 => { scalar: "<int64>" },
     u64 => { scalar: "<int64>" },
     f32 => { scalar: "<float32>" },
@@ -168,12 +342,49 @@ _to_edgeql_and_to_edge_scalar_impls!(
     bool => { scalar: "<bool>" },
     serde_json::Value => { scalar: "<json>" },
     uuid::Uuid => { scalar:"<uuid>"},
+    #[cfg(feature = "chrono")]
     chrono::DateTime<chrono::Utc> => { scalar: "<datetime>"},
-    chrono::DateTime<chrono::Local> => { scalar: "<cal::local_datetime>"},
-    chrono::Duration => { scalar : "<duration>"},
+    #[cfg(feature = "chrono")]
+    chrono::DateTime<chrono::Local> => { scalar: "<datetime>"},
+    #[cfg(feature = "chrono")]
+    chrono::NaiveDateTime => { scalar: "<cal::local_datetime>"},
+    #[cfg(feature = "chrono")]
+    chrono::Duration => { scalar: "<duration>"},
+    #[cfg(feature = "chrono")]
     chrono::Date<chrono::Local> => { scalar: "<cal::local_date>"},
+    #[cfg(feature = "chrono")]
     chrono::NaiveTime => { scalar: "<cal::local_time>"},
+    #[cfg(feature = "chrono")]
     chrono::NaiveDate => { scalar: "<cal::local_date>"},
+    #[cfg(feature = "time")]
+    time::OffsetDateTime => { scalar: "<datetime>"},
+    #[cfg(feature = "time")]
+    time::PrimitiveDateTime => { scalar: "<cal::local_datetime>"},
+    #[cfg(feature = "time")]
+    time::Duration => { scalar: "<duration>"},
+    #[cfg(feature = "time")]
+    time::Date => { scalar: "<cal::local_date>"},
+    #[cfg(feature = "time")]
+    time::Time => { scalar: "<cal::local_time>"},
+    #[cfg(feature = "num-bigint")]
+    num_bigint::BigInt => { scalar: "<bigint>"},
+    #[cfg(feature = "rust_decimal")]
+    rust_decimal::Decimal => { scalar: "<decimal>"},
+);
+
+_to_edge_scalar_impls!(
+    edgedb_protocol::model::BigInt => { scalar: "<bigint>"},
+    edgedb_protocol::model::Decimal => { scalar: "<decimal>"},
+    edgedb_protocol::model::RelativeDuration => { scalar: "<cal::relative_duration>"},
+    edgedb_protocol::model::DateDuration => { scalar: "<cal::date_duration>"},
+);
+
+_tuple_impls!(
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
 );
 
 impl ToEdgeScalar for () {
@@ -196,7 +407,7 @@ impl<T: ToEdgeQl> ToEdgeQl for Vec<T> {
 
 impl<T: ToEdgeScalar + Default> ToEdgeScalar for Vec<T> {
     fn scalar() -> String {
-        format!("<array{}>", T::scalar())
+        T::vec_scalar()
     }
 }
 
@@ -206,6 +417,12 @@ impl<T> ToEdgeShape for Vec<T> {
     }
 }
 
+impl<T: ToEdgeShape> ToEdgeShape for Option<T> {
+    fn shape() -> String {
+        T::shape()
+    }
+}
+
 impl ToEdgeValue for () {
     fn to_edge_value(&self) -> Value {
         Value::Nothing
@@ -226,7 +443,15 @@ impl ToEdgeValue for i8 {
 
 impl ToEdgeValue for u8 {
     fn to_edge_value(&self) -> Value {
-        Value::Int16(*self as i16)
+        Value::Int16(i16::from(*self))
+    }
+
+    fn vec_edge_value(items: &[Self]) -> Value {
+        models::bytes::bytes_value(items)
+    }
+
+    fn try_vec_edge_value(items: &[Self]) -> Result<Value, ValueOverflow> {
+        Ok(models::bytes::bytes_value(items))
     }
 }
 
@@ -238,7 +463,7 @@ impl ToEdgeValue for i16 {
 
 impl ToEdgeValue for u16 {
     fn to_edge_value(&self) -> Value {
-        Value::Int16(*self as i16)
+        Value::Int32(i32::from(*self))
     }
 }
 
@@ -251,7 +476,7 @@ impl ToEdgeValue for i32 {
 
 impl ToEdgeValue for u32 {
     fn to_edge_value(&self) -> Value {
-        Value::Int32(*self as i32)
+        Value::Int64(i64::from(*self))
     }
 }
 
@@ -263,8 +488,15 @@ impl ToEdgeValue for i64 {
 
 
 impl ToEdgeValue for u64 {
+    /// ⚠️ panics when the value is greater than __i64::MAX__, use __try_to_edge_value__ to handle the overflow
     fn to_edge_value(&self) -> Value {
-        Value::Int64(*self as i64)
+        self.try_to_edge_value().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn try_to_edge_value(&self) -> Result<Value, ValueOverflow> {
+        i64::try_from(*self)
+            .map(Value::Int64)
+            .map_err(|_| ValueOverflow::new(self, "int64"))
     }
 }
 
@@ -288,11 +520,27 @@ impl ToEdgeValue for bool {
 
 impl<T: ToEdgeValue> ToEdgeValue for Vec<T> {
     fn to_edge_value(&self) -> Value {
-        Value::Array(
-            self.iter()
-                .map(|t| t.to_edge_value())
-                .collect::<Vec<Value>>(),
-        )
+        T::vec_edge_value(self)
+    }
+
+    fn try_to_edge_value(&self) -> Result<Value, ValueOverflow> {
+        T::try_vec_edge_value(self)
+    }
+}
+
+impl<T: ToEdgeValue> ToEdgeValue for Option<T> {
+    fn to_edge_value(&self) -> Value {
+        match self {
+            Some(v) => v.to_edge_value(),
+            None => Value::Nothing,
+        }
+    }
+
+    fn try_to_edge_value(&self) -> Result<Value, ValueOverflow> {
+        match self {
+            Some(v) => v.try_to_edge_value(),
+            None => Ok(Value::Nothing),
+        }
     }
 }
 
@@ -307,3 +555,88 @@ impl ToEdgeValue for Uuid {
         Value::Uuid(Uuid::from_u128(self.as_u128()))
     }
 }
+
+// arbitrary precision numbers have no edgeql literal : they are always passed as query parameters
+impl ToEdgeQl for edgedb_protocol::model::BigInt {
+    fn to_edgeql(&self) -> EdgeQl {
+        EdgeQl::default()
+    }
+}
+
+impl ToEdgeQl for edgedb_protocol::model::Decimal {
+    fn to_edgeql(&self) -> EdgeQl {
+        EdgeQl::default()
+    }
+}
+
+// calendar durations have no edgeql literal : they are always passed as query parameters
+impl ToEdgeQl for edgedb_protocol::model::RelativeDuration {
+    fn to_edgeql(&self) -> EdgeQl {
+        EdgeQl::default()
+    }
+}
+
+impl ToEdgeQl for edgedb_protocol::model::DateDuration {
+    fn to_edgeql(&self) -> EdgeQl {
+        EdgeQl::default()
+    }
+}
+
+impl ToEdgeValue for edgedb_protocol::model::RelativeDuration {
+    fn to_edge_value(&self) -> Value {
+        Value::RelativeDuration(*self)
+    }
+}
+
+impl ToEdgeValue for edgedb_protocol::model::DateDuration {
+    fn to_edge_value(&self) -> Value {
+        Value::DateDuration(*self)
+    }
+}
+
+impl ToEdgeValue for edgedb_protocol::model::BigInt {
+    fn to_edge_value(&self) -> Value {
+        Value::BigInt(self.clone())
+    }
+}
+
+impl ToEdgeValue for edgedb_protocol::model::Decimal {
+    fn to_edge_value(&self) -> Value {
+        Value::Decimal(self.clone())
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl ToEdgeValue for num_bigint::BigInt {
+    /// ⚠️ panics when the value is out of the edgeDB bigint range, use __try_to_edge_value__ to handle the overflow
+    fn to_edge_value(&self) -> Value {
+        self.try_to_edge_value().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn try_to_edge_value(&self) -> Result<Value, ValueOverflow> {
+        edgedb_protocol::model::BigInt::try_from(self.clone())
+            .map(Value::BigInt)
+            .map_err(|_| ValueOverflow::new(self, "bigint"))
+    }
+}
+
+/// rust_decimal::Decimal can't be decoded from a result :
+/// results are decoded by edgedb-protocol, whose Queryable trait can't be implemented for a foreign type,
+/// a decimal result field must be an __edgedb_query::RustDecimal__ (or an __edgedb_protocol::model::Decimal__)
+#[cfg(feature = "rust_decimal")]
+impl ToEdgeValue for rust_decimal::Decimal {
+    /// ⚠️ panics when the value is out of the edgeDB decimal range, use __try_to_edge_value__ to handle the overflow
+    fn to_edge_value(&self) -> Value {
+        self.try_to_edge_value().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn try_to_edge_value(&self) -> Result<Value, ValueOverflow> {
+        use std::str::FromStr;
+
+        bigdecimal::BigDecimal::from_str(self.to_string().as_str())
+            .ok()
+            .and_then(|big_decimal| edgedb_protocol::model::Decimal::try_from(big_decimal).ok())
+            .map(Value::Decimal)
+            .ok_or_else(|| ValueOverflow::new(self, "decimal"))
+    }
+}
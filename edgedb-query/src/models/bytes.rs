@@ -0,0 +1,136 @@
+use edgedb_protocol::codec::STD_BYTES;
+use edgedb_protocol::descriptors::{Descriptor, TypePos};
+use edgedb_protocol::errors::DecodeError;
+use edgedb_protocol::queryable::{Decoder, DescriptorContext, DescriptorMismatch, Queryable};
+use edgedb_protocol::value::Value;
+use crate::{EdgeQl, EdgeResult, ToEdgeQl, ToEdgeScalar, ToEdgeShape, ToEdgeValue};
+
+const BYTES_SCALAR: &str = "<bytes>";
+const BYTES_TYPE_NAME: &str = "std::bytes";
+
+/// Bytes represents an edgeDB __bytes__ value (a binary blob)
+///
+/// A __Vec<u8>__ is also encoded as bytes, __Bytes__ makes the intent explicit.
+/// Bytes results can only be decoded as __Bytes__ : edgedb-protocol decodes neither __Vec<u8>__ nor __bytes::Bytes__
+///
+///<br>
+///
+/// ## Examples
+///
+///``` rust
+///     use edgedb_protocol::value::Value;
+///     use edgedb_query::{Bytes, ToEdgeScalar, ToEdgeValue};
+///
+///     let bytes = Bytes::from(vec![0x01, 0xff]);
+///
+///     assert_eq!(Bytes::scalar(), "<bytes>");
+///     assert_eq!(bytes.to_edge_value(), Value::Bytes(vec![0x01, 0xff].into()));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Bytes(pub Vec<u8>);
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<&[u8]> for Bytes {
+    fn from(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    fn from(bytes: Bytes) -> Self {
+        bytes.0
+    }
+}
+
+impl AsRef<[u8]> for Bytes {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl ToEdgeScalar for Bytes {
+    fn scalar() -> String {
+        BYTES_SCALAR.to_owned()
+    }
+}
+
+impl ToEdgeValue for Bytes {
+    fn to_edge_value(&self) -> Value {
+        bytes_value(&self.0)
+    }
+}
+
+// bytes have no edgeql literal : they are always passed as query parameters
+impl ToEdgeQl for Bytes {
+    fn to_edgeql(&self) -> EdgeQl {
+        EdgeQl::default()
+    }
+}
+
+impl ToEdgeShape for Bytes {
+    fn shape() -> String {
+        String::default()
+    }
+}
+
+impl EdgeResult for Bytes {
+    fn returning_fields() -> Vec<&'static str> {
+        vec![]
+    }
+}
+
+impl Queryable for Bytes {
+    fn decode(_decoder: &Decoder, buf: &[u8]) -> Result<Self, DecodeError> {
+        Ok(Bytes(buf.to_vec()))
+    }
+
+    fn check_descriptor(ctx: &DescriptorContext, type_pos: TypePos) -> Result<(), DescriptorMismatch> {
+        let desc = ctx.get(type_pos)?;
+
+        match desc {
+            Descriptor::Scalar(scalar) => Self::check_descriptor(ctx, scalar.base_type_pos),
+            Descriptor::BaseScalar(base) if base.id == STD_BYTES => Ok(()),
+            _ => Err(ctx.wrong_type(desc, BYTES_TYPE_NAME)),
+        }
+    }
+}
+
+impl ToEdgeScalar for bytes::Bytes {
+    fn scalar() -> String {
+        BYTES_SCALAR.to_owned()
+    }
+}
+
+impl ToEdgeValue for bytes::Bytes {
+    fn to_edge_value(&self) -> Value {
+        bytes_value(self)
+    }
+}
+
+impl ToEdgeQl for bytes::Bytes {
+    fn to_edgeql(&self) -> EdgeQl {
+        EdgeQl::default()
+    }
+}
+
+impl ToEdgeShape for bytes::Bytes {
+    fn shape() -> String {
+        String::default()
+    }
+}
+
+impl EdgeResult for bytes::Bytes {
+    fn returning_fields() -> Vec<&'static str> {
+        vec![]
+    }
+}
+
+/// returns the edgeDB bytes value of a binary blob (e.g. a __Vec<u8>__)
+pub fn bytes_value(bytes: &[u8]) -> Value {
+    Value::Bytes(bytes.to_vec())
+}
@@ -0,0 +1,114 @@
+use std::ops::Deref;
+use std::str::FromStr;
+use edgedb_protocol::codec::STD_DECIMAL;
+use edgedb_protocol::descriptors::{Descriptor, TypePos};
+use edgedb_protocol::errors::{decode_error, DecodeError};
+use edgedb_protocol::queryable::{Decoder, DescriptorContext, DescriptorMismatch, Queryable};
+use edgedb_protocol::value::Value;
+use crate::{EdgeQl, EdgeResult, ToEdgeQl, ToEdgeScalar, ToEdgeShape, ToEdgeValue, ValueOverflow};
+
+const DECIMAL_TYPE_NAME: &str = "std::decimal";
+
+/// RustDecimal represents an edgeDB __decimal__ value as a __rust_decimal::Decimal__
+///
+/// A __rust_decimal::Decimal__ field can be used as a query parameter but can't be decoded from a result :
+/// edgedb-protocol's Queryable trait can't be implemented for a foreign type.
+/// A decimal result field can be declared as __RustDecimal__ instead,
+/// the decoding fails when the edgeDB decimal is out of the __rust_decimal::Decimal__ range
+///
+///<br>
+///
+/// ## Examples
+///
+///``` rust
+///     use std::str::FromStr;
+///     use edgedb_query::{RustDecimal, ToEdgeScalar};
+///
+///     let decimal = RustDecimal::from(rust_decimal::Decimal::from_str("3.14").unwrap());
+///
+///     assert_eq!(RustDecimal::scalar(), "<decimal>");
+///     assert_eq!(decimal.to_string(), "3.14");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RustDecimal(pub rust_decimal::Decimal);
+
+impl From<rust_decimal::Decimal> for RustDecimal {
+    fn from(decimal: rust_decimal::Decimal) -> Self {
+        Self(decimal)
+    }
+}
+
+impl From<RustDecimal> for rust_decimal::Decimal {
+    fn from(decimal: RustDecimal) -> Self {
+        decimal.0
+    }
+}
+
+impl Deref for RustDecimal {
+    type Target = rust_decimal::Decimal;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for RustDecimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ToEdgeScalar for RustDecimal {
+    fn scalar() -> String {
+        rust_decimal::Decimal::scalar()
+    }
+}
+
+impl ToEdgeValue for RustDecimal {
+    /// ⚠️ panics when the value is out of the edgeDB decimal range, use __try_to_edge_value__ to handle the overflow
+    fn to_edge_value(&self) -> Value {
+        self.0.to_edge_value()
+    }
+
+    fn try_to_edge_value(&self) -> Result<Value, ValueOverflow> {
+        self.0.try_to_edge_value()
+    }
+}
+
+impl ToEdgeQl for RustDecimal {
+    fn to_edgeql(&self) -> EdgeQl {
+        self.0.to_edgeql()
+    }
+}
+
+impl ToEdgeShape for RustDecimal {
+    fn shape() -> String {
+        String::default()
+    }
+}
+
+impl EdgeResult for RustDecimal {
+    fn returning_fields() -> Vec<&'static str> {
+        vec![]
+    }
+}
+
+impl Queryable for RustDecimal {
+    fn decode(decoder: &Decoder, buf: &[u8]) -> Result<Self, DecodeError> {
+        let big_decimal = bigdecimal::BigDecimal::decode(decoder, buf)?;
+
+        rust_decimal::Decimal::from_str(big_decimal.to_string().as_str())
+            .map(RustDecimal)
+            .map_err(decode_error)
+    }
+
+    fn check_descriptor(ctx: &DescriptorContext, type_pos: TypePos) -> Result<(), DescriptorMismatch> {
+        let desc = ctx.get(type_pos)?;
+
+        match desc {
+            Descriptor::Scalar(scalar) => Self::check_descriptor(ctx, scalar.base_type_pos),
+            Descriptor::BaseScalar(base) if base.id == STD_DECIMAL => Ok(()),
+            _ => Err(ctx.wrong_type(desc, DECIMAL_TYPE_NAME)),
+        }
+    }
+}
@@ -0,0 +1,317 @@
+use std::fmt::{Display, Formatter, Write};
+use std::marker::PhantomData;
+use bytes::{Buf, BytesMut};
+use edgedb_protocol::codec::{scalar_codec, STD_DECIMAL};
+use edgedb_protocol::model::{Decimal, LocalDatetime};
+use edgedb_protocol::value::Value;
+use serde::de::DeserializeOwned;
+use crate::{EdgeResult, ToEdgeShape, ValueOverflow};
+
+const DECIMAL: &str = "decimal";
+
+/// DynamicShapeError represents an invalid dynamic shape field selection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DynamicShapeError {
+    /// the field is not one of the query result returning fields
+    UnknownField(String),
+    /// no field has been selected
+    NoField,
+}
+
+impl Display for DynamicShapeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DynamicShapeError::UnknownField(field) => write!(f, "Unknown result field '{field}'"),
+            DynamicShapeError::NoField => write!(f, "At least one result field must be selected"),
+        }
+    }
+}
+
+impl std::error::Error for DynamicShapeError {}
+
+/// DynamicShape represents a subset of a query result shape selected at runtime
+///
+/// __R__ : the query result whose fields are selected
+///
+///<br>
+///
+/// ## Examples
+///
+///``` rust
+///     use edgedb_query::{DynamicShape, EdgeResult, ToEdgeShape};
+///
+///     struct UserResult;
+///
+///     impl ToEdgeShape for UserResult {
+///         fn shape() -> String {
+///             "{id,name,email,friend : {id,name}}".to_owned()
+///         }
+///     }
+///
+///     impl EdgeResult for UserResult {
+///         fn returning_fields() -> Vec<&'static str> {
+///             vec!["id", "name", "email", "friend"]
+///         }
+///     }
+///
+///     let shape = DynamicShape::<UserResult>::parse("friend,name").unwrap();
+///
+///     assert_eq!(shape.shape(), "{name,friend : {id,name}}");
+///     assert!(DynamicShape::<UserResult>::parse("name,password").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DynamicShape<R: ToEdgeShape + EdgeResult> {
+    fields: Vec<String>,
+    _result: PhantomData<R>,
+}
+
+impl<R: ToEdgeShape + EdgeResult> DynamicShape<R> {
+
+    /// Create a dynamic shape selecting the given fields
+    ///
+    /// __returns__ : an error if a field is not one of R returning fields
+    pub fn new<S: AsRef<str>>(fields: &[S]) -> Result<Self, DynamicShapeError> {
+        let returning_fields = R::returning_fields();
+
+        let mut selected: Vec<String> = vec![];
+
+        for field in fields {
+            let field = field.as_ref().trim();
+
+            if !returning_fields.contains(&field) {
+                return Err(DynamicShapeError::UnknownField(field.to_owned()));
+            }
+
+            if !selected.iter().any(|f| f == field) {
+                selected.push(field.to_owned());
+            }
+        }
+
+        if selected.is_empty() {
+            return Err(DynamicShapeError::NoField);
+        }
+
+        Ok(Self {
+            fields: selected,
+            _result: PhantomData,
+        })
+    }
+
+    /// Create a dynamic shape from a comma separated list of fields (e.g. __name,email__)
+    pub fn parse(fields: &str) -> Result<Self, DynamicShapeError> {
+        let fields = fields
+            .split(',')
+            .filter(|f| !f.trim().is_empty())
+            .collect::<Vec<&str>>();
+
+        Self::new(&fields)
+    }
+
+    /// Create a dynamic shape selecting all R fields
+    pub fn all() -> Self {
+        Self {
+            fields: R::returning_fields().into_iter().map(String::from).collect(),
+            _result: PhantomData,
+        }
+    }
+
+    /// returns the selected fields
+    pub fn fields(&self) -> Vec<&str> {
+        self.fields.iter().map(String::as_str).collect()
+    }
+
+    /// returns the shape of R restricted to the selected fields, in R fields order
+    pub fn shape(&self) -> String {
+        let elements = shape_elements(R::shape().as_str())
+            .into_iter()
+            .filter(|(name, _)| self.fields.contains(name))
+            .map(|(_, element)| element)
+            .collect::<Vec<String>>();
+
+        format!("{{{}}}", elements.join(","))
+    }
+
+    /// Decode a query result value into a struct whose fields are optional (e.g. __Option<String>__)
+    pub fn decode<T: DeserializeOwned>(&self, value: &Value) -> Result<T, serde_json::Error> {
+        let json = to_json(value).map_err(serde::de::Error::custom)?;
+        serde_json::from_value(json)
+    }
+
+    /// Decode a query result value into a json value
+    pub fn decode_json(&self, value: &Value) -> Result<serde_json::Value, ValueOverflow> {
+        to_json(value)
+    }
+}
+
+/// Split a shape into its top level elements
+///
+/// __returns__ : the list of (field name, element statement)
+fn shape_elements(shape: &str) -> Vec<(String, String)> {
+    let shape = shape.trim();
+
+    let inner = shape.strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .unwrap_or(shape);
+
+    let mut elements = vec![];
+    let mut current = String::default();
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+
+    for c in inner.chars() {
+        match (c, quote) {
+            (_, Some(q)) if c == q => quote = None,
+            (_, Some(_)) => {}
+            ('\'' | '"', None) => quote = Some(c),
+            ('{' | '(' | '[', None) => depth += 1,
+            ('}' | ')' | ']', None) => depth -= 1,
+            (',', None) if depth == 0 => {
+                elements.push(current.trim().to_owned());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+
+    if !current.trim().is_empty() {
+        elements.push(current.trim().to_owned());
+    }
+
+    elements.into_iter()
+        .map(|element| {
+            let name = element
+                .split(|c: char| c.is_whitespace() || c == ':')
+                .next()
+                .unwrap_or_default()
+                .to_owned();
+            (name, element)
+        })
+        .collect()
+}
+
+/// Convert an edgedb value into a json value
+///
+/// Big integers and decimals are converted into json strings, to keep their precision,
+/// and datetimes into RFC 3339 strings, as expected by the server json casts
+///
+/// __returns__ : an error if a decimal has too many digits to be read
+pub fn to_json(value: &Value) -> Result<serde_json::Value, ValueOverflow> {
+    use serde_json::Value as Json;
+
+    let json = match value {
+        Value::Nothing => Json::Null,
+        Value::Uuid(v) => Json::String(v.to_string()),
+        Value::Str(v) => Json::String(v.clone()),
+        Value::Int16(v) => Json::from(*v),
+        Value::Int32(v) => Json::from(*v),
+        Value::Int64(v) => Json::from(*v),
+        Value::Float32(v) => Json::from(*v),
+        Value::Float64(v) => Json::from(*v),
+        Value::Bool(v) => Json::Bool(*v),
+        Value::Bytes(v) => Json::from(v.clone()),
+        Value::Json(v) => serde_json::from_str(v).unwrap_or(Json::Null),
+        Value::Enum(v) => Json::String((**v).to_owned()),
+        Value::Datetime(v) => Json::String(format!("{}Z", rfc3339_text(LocalDatetime::from_micros(v.to_micros())))),
+        Value::LocalDatetime(v) => Json::String(rfc3339_text(*v)),
+        Value::LocalDate(v) => Json::String(v.to_string()),
+        Value::LocalTime(v) => Json::String(v.to_string()),
+        Value::Duration(v) => Json::String(v.to_string()),
+        Value::RelativeDuration(v) => Json::String(v.to_string()),
+        Value::DateDuration(v) => Json::String(v.to_string()),
+        Value::BigInt(v) => Json::String(v.to_string()),
+        Value::Decimal(v) => Json::String(decimal_text(v)?),
+        Value::ConfigMemory(v) => Json::String(v.to_string()),
+        Value::Set(values) | Value::Array(values) | Value::Tuple(values) => {
+            Json::Array(values.iter().map(to_json).collect::<Result<_, _>>()?)
+        }
+        Value::Object { shape, fields } => {
+            let map = shape.elements.iter()
+                .zip(fields.iter())
+                .filter(|(el, _)| !el.flag_implicit)
+                .map(|(el, v)| Ok((el.name.clone(), v.as_ref().map(to_json).transpose()?.unwrap_or(Json::Null))))
+                .collect::<Result<_, ValueOverflow>>()?;
+            Json::Object(map)
+        }
+        Value::NamedTuple { shape, fields } => {
+            let map = shape.elements.iter()
+                .zip(fields.iter())
+                .map(|(el, v)| Ok((el.name.clone(), to_json(v)?)))
+                .collect::<Result<_, ValueOverflow>>()?;
+            Json::Object(map)
+        }
+        Value::Range(range) => {
+            let mut map = serde_json::Map::new();
+            if range.is_empty() {
+                map.insert("empty".to_owned(), Json::Bool(true));
+            } else {
+                map.insert("lower".to_owned(), range.lower().map(|v| to_json(v)).transpose()?.unwrap_or(Json::Null));
+                map.insert("upper".to_owned(), range.upper().map(|v| to_json(v)).transpose()?.unwrap_or(Json::Null));
+                map.insert("inc_lower".to_owned(), Json::Bool(range.inc_lower()));
+                map.insert("inc_upper".to_owned(), Json::Bool(range.inc_upper()));
+            }
+            Json::Object(map)
+        }
+        // a sparse object is only sent as a configuration input, never as a query parameter nor result
+        Value::SparseObject(_) => Json::Null,
+    };
+
+    Ok(json)
+}
+
+/// returns the RFC 3339 text representation of a datetime, without zone (e.g. __2022-01-31T10:30:00.000000__)
+fn rfc3339_text(value: LocalDatetime) -> String {
+    let micros = value.time().to_micros();
+    let secs = micros / 1_000_000;
+
+    format!("{}T{:02}:{:02}:{:02}.{:06}", value.date(), secs / 3600, secs / 60 % 60, secs % 60, micros % 1_000_000)
+}
+
+/// returns the decimal text representation (e.g. __-12.50__), read from the decimal wire format :
+/// the number of digits, the weight of the first digit, the sign, the scale, then the base 10000 digits
+fn decimal_text(value: &Decimal) -> Result<String, ValueOverflow> {
+    let mut buf = BytesMut::new();
+    let value = Value::Decimal(value.clone());
+    let overflow = || ValueOverflow::new(format!("{value:?}"), DECIMAL);
+
+    scalar_codec(&STD_DECIMAL)
+        .map_err(|_| overflow())?
+        .encode(&mut buf, &value)
+        .map_err(|_| overflow())?;
+
+    let nb_digits = buf.get_u16() as i32;
+    let weight = buf.get_i16() as i32;
+    let negative = buf.get_u16() == 0x4000;
+    let scale = buf.get_u16() as usize;
+    let digits = (0..nb_digits).map(|_| buf.get_u16()).collect::<Vec<u16>>();
+
+    let digit = |idx: i32| if (0..nb_digits).contains(&idx) { digits[idx as usize] } else { 0 };
+
+    let mut text = String::new();
+
+    if negative {
+        text.push('-');
+    }
+
+    if weight < 0 {
+        text.push('0');
+    } else {
+        let _ = write!(text, "{}", digit(0));
+        (1..=weight).for_each(|idx| { let _ = write!(text, "{:04}", digit(idx)); });
+    }
+
+    if scale > 0 {
+        let mut fraction = String::new();
+        let mut idx = weight + 1;
+        while fraction.len() < scale {
+            let _ = write!(fraction, "{:04}", digit(idx));
+            idx += 1;
+        }
+        fraction.truncate(scale);
+        text.push('.');
+        text.push_str(&fraction);
+    }
+
+    Ok(text)
+}
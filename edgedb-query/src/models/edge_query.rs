@@ -1,6 +1,7 @@
 use edgedb_protocol::common::Cardinality;
 use crate::ToEdgeQl;
 use crate::ToEdgeValue;
+use crate::ValueOverflow;
 use edgedb_protocol::value::Value;
 
 /// EgdeQuery represents a edgeDB query.
@@ -86,4 +87,13 @@ pub trait ToEdgeQuery: ToEdgeQl + ToEdgeValue {
     fn to_edge_query_with_cardinality(&self, cardinality: Cardinality) -> EdgeQuery {
         self.to_edge_query().with_cardinality(cardinality)
     }
+
+    /// Convert a given struct into a EdgeQuery struct, failing when an argument overflows its edgeDB type
+    fn try_to_edge_query(&self) -> Result<EdgeQuery, ValueOverflow> {
+        Ok(EdgeQuery {
+            query: self.to_edgeql().to_string(),
+            args: Some(self.try_to_edge_value()?),
+            cardinality: Cardinality::Many
+        })
+    }
 }
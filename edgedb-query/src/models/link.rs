@@ -0,0 +1,176 @@
+use std::marker::PhantomData;
+use edgedb_protocol::model::Uuid as EdgeUuid;
+use edgedb_protocol::value::Value;
+use uuid::Uuid;
+use crate::{ToEdgeScalar, ToEdgeValue};
+
+/// ToEdgeLink renders the statement selecting the objects targeted by a link
+pub trait ToEdgeLink {
+    /// returns the select statement of the linked objects
+    ///
+    /// __param__ : the query parameter holding the objects ids
+    fn link_statement(param: &str) -> String {
+        Self::link_statement_with_properties(param, "")
+    }
+
+    /// returns the select statement of the linked objects, setting the given link properties
+    ///
+    /// __param__ : the query parameter holding the objects ids
+    ///
+    /// __properties__ : the link properties assignments (e.g. __@since := (select <datetime>$since)__)
+    fn link_statement_with_properties(param: &str, properties: &str) -> String;
+}
+
+/// Link represents a reference to an existing object, identified by its id
+///
+/// __T__ : the linked object type, whose scalar gives the target table (e.g. __<users::User>__)
+///
+///<br>
+///
+/// ## Examples
+///
+///``` rust
+///     use edgedb_query::{Link, ToEdgeScalar};
+///     use edgedb_query::models::link::ToEdgeLink;
+///
+///     struct User;
+///
+///     impl ToEdgeScalar for User {
+///         fn scalar() -> String {
+///             "<users::User>".to_owned()
+///         }
+///     }
+///
+///     assert_eq!(Link::<User>::link_statement("owner"), "(select users::User filter .id = <uuid>$owner)");
+///
+///     assert_eq!(
+///         Link::<User>::link_statement_with_properties("owner", "@since := (select <datetime>$since)"),
+///         "(select users::User { @since := (select <datetime>$since) } filter .id = <uuid>$owner)"
+///     );
+/// ```
+#[derive(Debug)]
+pub struct Link<T: ToEdgeScalar> {
+    pub id: Uuid,
+    _target: PhantomData<T>,
+}
+
+impl<T: ToEdgeScalar> Link<T> {
+    pub fn new(id: Uuid) -> Self {
+        Self { id, _target: PhantomData }
+    }
+}
+
+impl<T: ToEdgeScalar> Clone for Link<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.id)
+    }
+}
+
+impl<T: ToEdgeScalar> From<Uuid> for Link<T> {
+    fn from(id: Uuid) -> Self {
+        Self::new(id)
+    }
+}
+
+impl<T: ToEdgeScalar> ToEdgeLink for Link<T> {
+    fn link_statement_with_properties(param: &str, properties: &str) -> String {
+        format!("(select {}{} filter .id = <uuid>${param})", target_table::<T>(), properties_shape(properties))
+    }
+}
+
+impl<T: ToEdgeScalar> ToEdgeScalar for Link<T> {
+    fn scalar() -> String {
+        "<uuid>".to_owned()
+    }
+}
+
+impl<T: ToEdgeScalar> ToEdgeValue for Link<T> {
+    fn to_edge_value(&self) -> Value {
+        Value::Uuid(EdgeUuid::from_u128(self.id.as_u128()))
+    }
+}
+
+/// Links represents references to a set of existing objects, identified by their ids
+///
+/// __T__ : the linked objects type, whose scalar gives the target table (e.g. __<users::User>__)
+///
+///<br>
+///
+/// ## Examples
+///
+///``` rust
+///     use edgedb_query::{Links, ToEdgeScalar};
+///     use edgedb_query::models::link::ToEdgeLink;
+///
+///     struct User;
+///
+///     impl ToEdgeScalar for User {
+///         fn scalar() -> String {
+///             "<users::User>".to_owned()
+///         }
+///     }
+///
+///     assert_eq!(
+///         Links::<User>::link_statement("friends"),
+///         "(select users::User filter .id in array_unpack(<array<uuid>>$friends))"
+///     );
+/// ```
+#[derive(Debug)]
+pub struct Links<T: ToEdgeScalar> {
+    pub ids: Vec<Uuid>,
+    _target: PhantomData<T>,
+}
+
+impl<T: ToEdgeScalar> Links<T> {
+    pub fn new(ids: Vec<Uuid>) -> Self {
+        Self { ids, _target: PhantomData }
+    }
+}
+
+impl<T: ToEdgeScalar> Clone for Links<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.ids.clone())
+    }
+}
+
+impl<T: ToEdgeScalar> From<Vec<Uuid>> for Links<T> {
+    fn from(ids: Vec<Uuid>) -> Self {
+        Self::new(ids)
+    }
+}
+
+impl<T: ToEdgeScalar> ToEdgeLink for Links<T> {
+    fn link_statement_with_properties(param: &str, properties: &str) -> String {
+        format!("(select {}{} filter .id in array_unpack(<array<uuid>>${param}))", target_table::<T>(), properties_shape(properties))
+    }
+}
+
+impl<T: ToEdgeScalar> ToEdgeScalar for Links<T> {
+    fn scalar() -> String {
+        "<array<uuid>>".to_owned()
+    }
+}
+
+impl<T: ToEdgeScalar> ToEdgeValue for Links<T> {
+    fn to_edge_value(&self) -> Value {
+        Value::Array(self.ids.iter().map(|id| Value::Uuid(EdgeUuid::from_u128(id.as_u128()))).collect())
+    }
+}
+
+/// returns the table name given by T scalar (e.g. __users::User__ for __<users::User>__)
+fn target_table<T: ToEdgeScalar>() -> String {
+    T::scalar()
+        .trim()
+        .trim_start_matches('<')
+        .trim_end_matches('>')
+        .to_owned()
+}
+
+/// returns the link properties shape (e.g. __ { @since := (select <datetime>$since) }__)
+fn properties_shape(properties: &str) -> String {
+    if properties.trim().is_empty() {
+        String::default()
+    } else {
+        format!(" {{ {} }}", properties.trim())
+    }
+}
@@ -1,2 +1,9 @@
 pub mod edge_query;
-pub mod query_result;
\ No newline at end of file
+pub mod query_result;
+pub mod dynamic_shape;
+pub mod link;
+pub mod bytes;
+#[cfg(feature = "rust_decimal")]
+pub mod decimal;
+pub mod range;
+pub mod tuple;
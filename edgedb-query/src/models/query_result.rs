@@ -1,8 +1,13 @@
 use crate::{EdgeQl, EdgeResult, ToEdgeQl, ToEdgeShape};
 use edgedb_derive::Queryable;
+use edgedb_protocol::descriptors::{Descriptor, ShapeElement, TypePos};
+use edgedb_protocol::errors::{DecodeError, ObjectSizeMismatch};
+use edgedb_protocol::queryable::{Decoder, DescriptorContext, DescriptorMismatch, Queryable};
+use edgedb_protocol::serialization::decode::DecodeTupleLike;
 use uuid::Uuid;
 
 const STRUCT_ID: &str = "{ id }";
+const ID: &str = "id";
 
 /// BasicResult represents the default edgeDB query result
 #[derive(Default, Queryable)]
@@ -26,4 +31,163 @@ impl EdgeResult for BasicResult {
     fn returning_fields() -> Vec<&'static str> {
         vec![]
     }
-}
\ No newline at end of file
+}
+
+impl QueryableFields for BasicResult {
+    fn fields_count() -> usize {
+        1
+    }
+
+    fn selects_id() -> bool {
+        true
+    }
+
+    fn decode_fields(decoder: &Decoder, elements: &mut DecodeTupleLike) -> Result<Self, DecodeError> {
+        Ok(Self {
+            id: Queryable::decode_optional(decoder, elements.read()?)?,
+        })
+    }
+
+    fn check_fields_descriptor(ctx: &DescriptorContext, elements: &[ShapeElement], idx: &mut usize) -> Result<(), DescriptorMismatch> {
+        check_field_descriptor::<Uuid>(ctx, elements, idx, "id")
+    }
+}
+
+/// QueryableFields trait represents a query result whose fields can be decoded
+/// as a part of an enclosing result object.
+///
+/// It is implemented by every `#[query_result]` struct so that it can be
+/// flattened into another result (`#[flatten]` or `extends`).
+pub trait QueryableFields: Sized {
+    /// returns the number of shape elements read by the struct
+    fn fields_count() -> usize;
+
+    /// returns true when the fields include the object id, directly or through a flattened result
+    fn selects_id() -> bool;
+
+    /// decode the struct fields from the enclosing object elements
+    fn decode_fields(decoder: &Decoder, elements: &mut DecodeTupleLike) -> Result<Self, DecodeError>;
+
+    /// check the struct fields against the enclosing object shape elements, starting at __idx__
+    fn check_fields_descriptor(ctx: &DescriptorContext, elements: &[ShapeElement], idx: &mut usize) -> Result<(), DescriptorMismatch>;
+}
+
+/// Check that the shape element at __idx__ is named __name__ and matches the type __T__
+pub fn check_field_descriptor<T: Queryable>(ctx: &DescriptorContext, elements: &[ShapeElement], idx: &mut usize, name: &str) -> Result<(), DescriptorMismatch> {
+    let el = elements.get(*idx)
+        .ok_or_else(|| ctx.field_number(elements.len(), *idx + 1))?;
+
+    if el.name != name {
+        return Err(ctx.wrong_field(name, &el.name));
+    }
+
+    *idx += 1;
+
+    T::check_descriptor(ctx, el.type_pos)
+}
+
+/// Open a result object and skip its implicit elements
+///
+/// __implicit_id__ : whether the object id is sent as an implicit element,
+/// i.e. the object is not a free object and its shape does not select the id
+///
+/// __returns__ : the object elements and the number of remaining (non implicit) elements
+pub fn open_object<'t>(decoder: &Decoder, buf: &'t [u8], implicit_id: bool) -> Result<(DecodeTupleLike<'t>, usize), DecodeError> {
+    let nb_elements = if buf.len() >= 4 {
+        u32::from_be_bytes([buf[0], buf[1], buf[2], buf[3]]) as usize
+    } else {
+        0
+    };
+
+    let mut elements = DecodeTupleLike::new_object(buf, nb_elements)?;
+
+    let mut nb_implicits = 0;
+
+    if decoder.has_implicit_tid {
+        elements.skip_element()?;
+        nb_implicits += 1;
+    }
+
+    if decoder.has_implicit_tname {
+        elements.skip_element()?;
+        nb_implicits += 1;
+    }
+
+    if implicit_id {
+        elements.skip_element()?;
+        nb_implicits += 1;
+    }
+
+    Ok((elements, nb_elements.saturating_sub(nb_implicits)))
+}
+
+/// Decode a result object whose fields are described by a QueryableFields implementation
+///
+/// __implicit_id__ : whether the object id is sent as an implicit element
+pub fn decode_object<T: QueryableFields>(decoder: &Decoder, buf: &[u8], implicit_id: bool) -> Result<T, DecodeError> {
+    let nb_fields = T::fields_count();
+
+    let (mut elements, present) = open_object(decoder, buf, implicit_id)?;
+
+    if present != nb_fields {
+        return Err(ObjectSizeMismatch.build());
+    }
+
+    T::decode_fields(decoder, &mut elements)
+}
+
+/// Get a result object shape elements
+///
+/// __implicit_id__ : whether the object id is expected as an implicit element
+///
+/// __returns__ : the shape elements and the index of the first non implicit element
+pub fn object_elements<'a>(ctx: &'a DescriptorContext, type_pos: TypePos, implicit_id: bool) -> Result<(&'a [ShapeElement], usize), DescriptorMismatch> {
+    let desc = ctx.get(type_pos)?;
+
+    let shape = match desc {
+        Descriptor::ObjectShape(shape) => shape,
+        _ => return Err(ctx.wrong_type(desc, "object")),
+    };
+
+    let is_implicit = |idx: usize| shape.elements.get(idx).is_some_and(|el| el.flag_implicit);
+
+    let mut idx = 0;
+
+    if ctx.has_implicit_tid {
+        if !is_implicit(idx) {
+            return Err(ctx.expected("implicit __tid__"));
+        }
+        idx += 1;
+    }
+
+    if ctx.has_implicit_tname {
+        if !is_implicit(idx) {
+            return Err(ctx.expected("implicit __tname__"));
+        }
+        idx += 1;
+    }
+
+    if implicit_id {
+        if !is_implicit(idx) || shape.elements[idx].name != ID {
+            return Err(ctx.expected("implicit id"));
+        }
+        idx += 1;
+    }
+
+    Ok((&shape.elements, idx))
+}
+
+/// Check a result object descriptor against a QueryableFields implementation
+///
+/// __implicit_id__ : whether the object id is expected as an implicit element
+pub fn check_object_descriptor<T: QueryableFields>(ctx: &DescriptorContext, type_pos: TypePos, implicit_id: bool) -> Result<(), DescriptorMismatch> {
+    let (elements, mut idx) = object_elements(ctx, type_pos, implicit_id)?;
+
+    T::check_fields_descriptor(ctx, elements, &mut idx)?;
+
+    if elements.len() != idx {
+        return Err(ctx.field_number(elements.len(), idx));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,273 @@
+use bytes::BytesMut;
+use edgedb_protocol::codec::{build_codec, scalar_codec, CAL_LOCAL_DATE, CAL_LOCAL_DATETIME, STD_DATETIME, STD_DECIMAL, STD_FLOAT32, STD_FLOAT64, STD_INT32, STD_INT64};
+use edgedb_protocol::descriptors::{BaseScalarTypeDescriptor, Descriptor, RangeTypeDescriptor, TypePos};
+use edgedb_protocol::errors::{DecodeError, ExtraData, Underflow};
+use edgedb_protocol::model::Range as EdgeRange;
+use edgedb_protocol::queryable::{Decoder, DescriptorContext, DescriptorMismatch, Queryable};
+use edgedb_protocol::value::Value;
+use uuid::Uuid;
+use crate::{EdgeQl, EdgeResult, ToEdgeQl, ToEdgeScalar, ToEdgeShape, ToEdgeValue, ValueOverflow};
+
+const RANGE_EMPTY: u8 = 0x01;
+const RANGE_LB_INC: u8 = 0x02;
+const RANGE_UB_INC: u8 = 0x04;
+const RANGE_LB_INF: u8 = 0x08;
+const RANGE_UB_INF: u8 = 0x10;
+const RANGE: &str = "range";
+
+/// Range represents an edgeDB range (__range<T>__) of values of type T
+///
+/// A missing bound (__None__) means the range is unbounded on that side
+///
+/// __T__ : the bound type, whose scalar must be one of __int32__, __int64__, __float32__, __float64__,
+/// __decimal__, __datetime__, __cal::local_datetime__ or __cal::local_date__
+///
+///<br>
+///
+/// ## Examples
+///
+///``` rust
+///     use edgedb_query::{Range, ToEdgeScalar};
+///
+///     let stay: Range<i64> = (1..5).into();
+///
+///     assert_eq!(Range::<i64>::scalar(), "<range<int64>>");
+///     assert_eq!(stay, Range::new(Some(1), Some(5)));
+///     assert!(stay.inc_lower && !stay.inc_upper);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Range<T> {
+    pub lower: Option<T>,
+    pub upper: Option<T>,
+    pub inc_lower: bool,
+    pub inc_upper: bool,
+    pub empty: bool,
+}
+
+impl<T> Range<T> {
+    /// returns the range [lower, upper)
+    pub fn new(lower: Option<T>, upper: Option<T>) -> Self {
+        Self { lower, upper, inc_lower: true, inc_upper: false, empty: false }
+    }
+
+    /// returns the range [lower, upper]
+    pub fn inclusive(lower: T, upper: T) -> Self {
+        Self { lower: Some(lower), upper: Some(upper), inc_lower: true, inc_upper: true, empty: false }
+    }
+
+    /// returns the empty range
+    pub fn empty() -> Self {
+        Self { lower: None, upper: None, inc_lower: false, inc_upper: false, empty: true }
+    }
+}
+
+impl<T> Default for Range<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T> From<std::ops::Range<T>> for Range<T> {
+    fn from(range: std::ops::Range<T>) -> Self {
+        Self::new(Some(range.start), Some(range.end))
+    }
+}
+
+impl<T> From<std::ops::RangeInclusive<T>> for Range<T> {
+    fn from(range: std::ops::RangeInclusive<T>) -> Self {
+        let (lower, upper) = range.into_inner();
+        Self::inclusive(lower, upper)
+    }
+}
+
+impl<T> From<std::ops::RangeFrom<T>> for Range<T> {
+    fn from(range: std::ops::RangeFrom<T>) -> Self {
+        Self::new(Some(range.start), None)
+    }
+}
+
+impl<T> From<std::ops::RangeTo<T>> for Range<T> {
+    fn from(range: std::ops::RangeTo<T>) -> Self {
+        Self::new(None, Some(range.end))
+    }
+}
+
+impl<T: Clone> From<EdgeRange<T>> for Range<T> {
+    fn from(range: EdgeRange<T>) -> Self {
+        if range.is_empty() {
+            return Self::empty();
+        }
+
+        Self {
+            lower: range.lower().cloned(),
+            upper: range.upper().cloned(),
+            inc_lower: range.inc_lower(),
+            inc_upper: range.inc_upper(),
+            empty: false,
+        }
+    }
+}
+
+impl<T: ToEdgeScalar> ToEdgeScalar for Range<T> {
+    fn scalar() -> String {
+        format!("<range{}>", T::scalar())
+    }
+}
+
+impl<T: ToEdgeValue> ToEdgeValue for Range<T> {
+    /// ⚠️ panics when a bound is out of the edgeDB type range, use __try_to_edge_value__ to handle the overflow
+    fn to_edge_value(&self) -> Value {
+        self.try_to_edge_value().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn try_to_edge_value(&self) -> Result<Value, ValueOverflow> {
+        if self.empty {
+            return Ok(Value::Range(EdgeRange::empty()));
+        }
+
+        let bound = |b: &Option<T>| b.as_ref().map(|v| v.try_to_edge_value()).transpose();
+
+        range_value(bound(&self.lower)?, bound(&self.upper)?, self.inc_lower, self.inc_upper)
+    }
+}
+
+// ranges have no edgeql literal : they are always passed as query parameters
+impl<T> ToEdgeQl for Range<T> {
+    fn to_edgeql(&self) -> EdgeQl {
+        EdgeQl::default()
+    }
+}
+
+impl<T> ToEdgeShape for Range<T> {
+    fn shape() -> String {
+        String::default()
+    }
+}
+
+impl<T> EdgeResult for Range<T> {
+    fn returning_fields() -> Vec<&'static str> {
+        vec![]
+    }
+}
+
+impl<T: Queryable> Queryable for Range<T> {
+    fn decode(decoder: &Decoder, buf: &[u8]) -> Result<Self, DecodeError> {
+        let (flags, mut buf) = buf.split_first().ok_or_else(|| Underflow.build())?;
+
+        if flags & RANGE_EMPTY != 0 {
+            return Ok(Self::empty());
+        }
+
+        let mut read_bound = |infinite: u8| -> Result<Option<T>, DecodeError> {
+            if flags & infinite != 0 {
+                return Ok(None);
+            }
+
+            if buf.len() < 4 {
+                return Err(Underflow.build());
+            }
+
+            let len = i32::from_be_bytes([buf[0], buf[1], buf[2], buf[3]]) as usize;
+            let element = buf.get(4..4 + len).ok_or_else(|| Underflow.build())?;
+
+            buf = &buf[4 + len..];
+
+            T::decode(decoder, element).map(Some)
+        };
+
+        let lower = read_bound(RANGE_LB_INF)?;
+        let upper = read_bound(RANGE_UB_INF)?;
+
+        if !buf.is_empty() {
+            return Err(ExtraData.build());
+        }
+
+        Ok(Self {
+            lower,
+            upper,
+            inc_lower: flags & RANGE_LB_INC != 0,
+            inc_upper: flags & RANGE_UB_INC != 0,
+            empty: false,
+        })
+    }
+
+    fn check_descriptor(ctx: &DescriptorContext, type_pos: TypePos) -> Result<(), DescriptorMismatch> {
+        let desc = ctx.get(type_pos)?;
+
+        match desc {
+            Descriptor::Range(range) => T::check_descriptor(ctx, range.type_pos),
+            _ => Err(ctx.wrong_type(desc, "range")),
+        }
+    }
+}
+
+/// returns the edgeDB range value of the given bounds
+///
+/// edgedb-protocol only builds __[lower, upper)__ and empty ranges,
+/// the other ranges are decoded from their wire format by the protocol range codec
+///
+/// Fails if a bound is not an __int32__, __int64__, __float32__, __float64__, __decimal__,
+/// __datetime__, __cal::local_datetime__ or __cal::local_date__ value, or if both bounds have not the same type
+pub fn range_value(lower: Option<Value>, upper: Option<Value>, inc_lower: bool, inc_upper: bool) -> Result<Value, ValueOverflow> {
+    let invalid = |bound: &Value| ValueOverflow::new(format!("{bound:?}"), RANGE);
+
+    let (lower, upper) = match (lower, upper) {
+        (Some(lower), Some(upper)) if inc_lower && !inc_upper => {
+            let scalar_id = bound_scalar_id(&lower).ok_or_else(|| invalid(&lower))?;
+            if bound_scalar_id(&upper) != Some(scalar_id) {
+                return Err(invalid(&upper));
+            }
+            return Ok(Value::Range(EdgeRange::from(Box::new(lower)..Box::new(upper))));
+        }
+        bounds => bounds,
+    };
+
+    let element_id = match lower.as_ref().or(upper.as_ref()) {
+        Some(bound) => bound_scalar_id(bound).ok_or_else(|| invalid(bound))?,
+        None => STD_INT64,
+    };
+
+    let overflow = || ValueOverflow::new(element_id, RANGE);
+
+    let element_codec = scalar_codec(&element_id).map_err(|_| overflow())?;
+
+    let mut buf = BytesMut::new();
+
+    let flags = (if inc_lower { RANGE_LB_INC } else { 0 })
+        | (if inc_upper { RANGE_UB_INC } else { 0 })
+        | (if lower.is_none() { RANGE_LB_INF } else { 0 })
+        | (if upper.is_none() { RANGE_UB_INF } else { 0 });
+
+    buf.extend_from_slice(&[flags]);
+
+    for bound in [lower, upper].iter().flatten() {
+        let mut element = BytesMut::new();
+        element_codec.encode(&mut element, bound).map_err(|_| invalid(bound))?;
+        buf.extend_from_slice(&(element.len() as i32).to_be_bytes());
+        buf.extend_from_slice(&element);
+    }
+
+    let descriptors = [
+        Descriptor::BaseScalar(BaseScalarTypeDescriptor { id: element_id }),
+        Descriptor::Range(RangeTypeDescriptor { id: Uuid::from_u128(0), type_pos: TypePos(0) }),
+    ];
+
+    build_codec(Some(TypePos(1)), &descriptors)
+        .map_err(|_| overflow())?
+        .decode(&buf)
+        .map_err(|_| overflow())
+}
+
+fn bound_scalar_id(bound: &Value) -> Option<Uuid> {
+    match bound {
+        Value::Int32(_) => Some(STD_INT32),
+        Value::Int64(_) => Some(STD_INT64),
+        Value::Float32(_) => Some(STD_FLOAT32),
+        Value::Float64(_) => Some(STD_FLOAT64),
+        Value::Decimal(_) => Some(STD_DECIMAL),
+        Value::Datetime(_) => Some(STD_DATETIME),
+        Value::LocalDatetime(_) => Some(CAL_LOCAL_DATETIME),
+        Value::LocalDate(_) => Some(CAL_LOCAL_DATE),
+        _ => None,
+    }
+}
@@ -0,0 +1,48 @@
+use edgedb_protocol::codec::NamedTupleShape;
+use edgedb_protocol::descriptors::{Descriptor, TupleElement, TypePos};
+use edgedb_protocol::errors::DecodeError;
+use edgedb_protocol::queryable::{DescriptorContext, DescriptorMismatch};
+use edgedb_protocol::serialization::decode::DecodeTupleLike;
+use edgedb_protocol::value::Value;
+
+/// returns the edgeDB named tuple value whose elements are named __names__
+///
+/// __names__ : the named tuple element names
+///
+/// __fields__ : the named tuple element values, in the same order as the names
+pub fn named_tuple_value(names: &[&str], fields: Vec<Value>) -> Value {
+    // the elements type positions are not part of the value shape
+    let elements = names.iter()
+        .map(|name| TupleElement { name: name.to_string(), type_pos: TypePos(0) })
+        .collect::<Vec<TupleElement>>();
+
+    Value::NamedTuple {
+        shape: NamedTupleShape::from(elements.as_slice()),
+        fields,
+    }
+}
+
+/// Open a named tuple result of __nb_elements__ elements
+pub fn open_named_tuple(buf: &[u8], nb_elements: usize) -> Result<DecodeTupleLike<'_>, DecodeError> {
+    DecodeTupleLike::new_tuple(buf, nb_elements)
+}
+
+/// Check that the descriptor at __type_pos__ is a named tuple whose elements are named __names__
+///
+/// __returns__ : the elements type positions, to check each element type
+pub fn check_named_tuple_descriptor(ctx: &DescriptorContext, type_pos: TypePos, names: &[&str]) -> Result<Vec<TypePos>, DescriptorMismatch> {
+    let desc = ctx.get(type_pos)?;
+
+    let Descriptor::NamedTuple(tuple) = desc else {
+        return Err(ctx.wrong_type(desc, "named tuple"));
+    };
+
+    if tuple.elements.len() != names.len() {
+        return Err(ctx.field_number(names.len(), tuple.elements.len()));
+    }
+
+    tuple.elements.iter()
+        .zip(names)
+        .map(|(el, name)| if el.name == *name { Ok(el.type_pos) } else { Err(ctx.wrong_field(name, &el.name)) })
+        .collect()
+}
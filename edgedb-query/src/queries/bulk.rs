@@ -0,0 +1,184 @@
+use edgedb_protocol::codec::ObjectShape;
+use edgedb_protocol::common::Cardinality;
+use edgedb_protocol::descriptors::{ShapeElement, TypePos};
+use edgedb_protocol::value::Value;
+use crate::{EdgeQl, ToEdgeQl, ToEdgeQuery, ToEdgeValue, ValueOverflow};
+use crate::models::dynamic_shape::to_json;
+
+const ITEMS: &str = "items";
+
+/// BulkInsert trait represents an insert query that can be repeated over a list of items
+/// in a single query.
+///
+/// It is implemented by every `#[insert_query]` struct without nested query, link, bytes or range fields
+/// nor unless conflict else query.
+#[diagnostic::on_unimplemented(
+    message = "{Self} can not be inserted in bulk",
+    note = "nested query, link, bytes and range fields and unless conflict else queries are not supported in bulk inserts"
+)]
+pub trait BulkInsert {
+    /// returns the insert statement of one item bound to __item__
+    ///
+    /// __item__ : the item whose unless conflict statement is rendered, if any
+    fn item_edgeql(item: Option<&Self>) -> String;
+
+    /// returns the bulk insert statement, each inserted item being bound to __item__
+    ///
+    /// __item__ : the item whose unless conflict statement is rendered, if any
+    fn bulk_edgeql(item: Option<&Self>) -> String;
+
+    /// returns the item parameters values (None values are omitted),
+    /// failing when a value overflows its edgeDB type
+    fn bulk_values(&self) -> Result<Vec<(String, Value)>, ValueOverflow>;
+}
+
+/// Build the bulk insert statement
+///
+/// __insert__ : the insert statement of one item
+///
+/// __shape__ : the result shape
+///
+/// ## Examples
+///
+/// ```
+/// use edgedb_query::queries::bulk::bulk_insert_statement;
+///
+/// let stmt = bulk_insert_statement("insert City {name := (<str>item['name']), }", "");
+///
+/// assert_eq!(stmt, "for item in json_array_unpack(<json>$items) union (insert City {name := (<str>item['name']), })");
+/// ```
+pub fn bulk_insert_statement(insert: &str, shape: &str) -> String {
+    let stmt = format!("for item in json_array_unpack(<json>${ITEMS}) union ({})", insert.trim());
+
+    if shape.is_empty() {
+        stmt
+    } else {
+        format!("select ({stmt}) {shape}")
+    }
+}
+
+/// returns the cast of an item json element to __scalar__
+///
+/// Big integers and decimals are sent as json strings to keep their precision : they are cast through str
+///
+/// ## Examples
+///
+/// ```
+/// use edgedb_query::queries::bulk::json_item_cast;
+///
+/// assert_eq!(json_item_cast("<int64>"), "<int64>");
+/// assert_eq!(json_item_cast("<decimal>"), "<decimal><str>");
+/// assert_eq!(json_item_cast("<array<bigint>>"), "<array<bigint>><array<str>>");
+/// ```
+pub fn json_item_cast(scalar: &str) -> String {
+    let mut json_scalar = String::new();
+    let mut name = String::new();
+
+    for c in scalar.chars().chain(std::iter::once(' ')) {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            name.push(c);
+            continue;
+        }
+
+        match name.as_str() {
+            "bigint" | "std::bigint" | "decimal" | "std::decimal" => json_scalar.push_str("str"),
+            _ => json_scalar.push_str(&name),
+        }
+        name.clear();
+        json_scalar.push(c);
+    }
+
+    let json_scalar = &json_scalar[..json_scalar.len() - 1];
+
+    if json_scalar == scalar {
+        scalar.to_owned()
+    } else {
+        format!("{scalar}{json_scalar}")
+    }
+}
+
+/// Build the statement inserting a list of items assigned to a multi link of a parent insert query
+///
+/// __param__ : the parameter holding the items json array
+///
+/// ## Examples
+///
+/// ```rust,ignore
+///     // renders : for item in json_array_unpack(<json>$wallets) union (insert users::Wallet {money := (<int16>item['money']), })
+///     let stmt = nested_bulk_insert_statement::<InsertWallet>("wallets");
+/// ```
+pub fn nested_bulk_insert_statement<T: BulkInsert>(param: &str) -> String {
+    format!("for item in json_array_unpack(<json>${param}) union ({})", T::item_edgeql(None).trim())
+}
+
+/// Encode a list of items as a json array value, failing when an item value overflows its edgeDB type
+pub fn bulk_items_value<T: BulkInsert>(items: &[T]) -> Result<Value, ValueOverflow> {
+    let items = items.iter()
+        .map(|item| {
+            let map = item.bulk_values()?
+                .iter()
+                .map(|(name, value)| Ok((name.clone(), to_json(value)?)))
+                .collect::<Result<_, ValueOverflow>>()?;
+            Ok(serde_json::Value::Object(map))
+        })
+        .collect::<Result<Vec<serde_json::Value>, ValueOverflow>>()?;
+
+    Ok(Value::Json(serde_json::Value::Array(items).to_string()))
+}
+
+/// InsertMany struct wraps a list of insert queries in order to insert all of them
+/// in a single round trip.
+///
+/// The items are encoded as a json array parameter named __items__.
+///
+/// An unless conflict statement is the same for all the items : it has no else query or its else query selects the conflicting object.
+///
+///<br>
+///
+/// ## Examples
+///
+/// ```rust,ignore
+///     let query = InsertMany::new(cities).to_edge_query();
+///
+///     client.query::<BasicResult, _>(query.query.as_str(), &query.args.unwrap()).await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct InsertMany<T: BulkInsert> {
+    pub items: Vec<T>,
+}
+
+impl<T: BulkInsert> InsertMany<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        Self { items }
+    }
+}
+
+impl<T: BulkInsert> ToEdgeQl for InsertMany<T> {
+    fn to_edgeql(&self) -> EdgeQl {
+        EdgeQl::new(T::bulk_edgeql(self.items.first()), false)
+    }
+}
+
+impl<T: BulkInsert> ToEdgeValue for InsertMany<T> {
+    fn to_edge_value(&self) -> Value {
+        self.try_to_edge_value().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn try_to_edge_value(&self) -> Result<Value, ValueOverflow> {
+        let shape: &[ShapeElement] = &[ShapeElement {
+            flag_implicit: false,
+            flag_link_property: false,
+            flag_link: false,
+            cardinality: Some(Cardinality::One),
+            name: ITEMS.to_string(),
+            type_pos: TypePos(0),
+        }];
+
+        Ok(Value::Object {
+            shape: ObjectShape::from(shape),
+            fields: vec![Some(bulk_items_value(&self.items)?)],
+        })
+    }
+}
+
+impl<T: BulkInsert> ToEdgeQuery for InsertMany<T> {}
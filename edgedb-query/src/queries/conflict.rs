@@ -92,6 +92,66 @@ impl Conflict<EmptyQuery> for UnlessConflict {
 /// }
 /// ```
 pub fn parse_conflict<T: ToEdgeQuery + Clone, R: Conflict<T>>(conflict: &R, on_fields: Vec<&str>) -> String {
+    let mut stmt = on_statement(on_fields);
+
+    if let Some(else_query)= conflict.else_query() {
+        stmt.push_str(ELSE);
+        stmt.push_str(else_query.to_edgeql().to_string().as_str());
+        stmt.push_str(CLOSE_PARENTHESIS);
+    }
+
+    stmt
+}
+
+/// parse a conflict whose else query selects the existing object into a string statement
+///
+/// ## Examples
+///
+/// ```
+///use edgedb_query::queries::conflict::parse_conflict_else_select;
+///
+///let stmt = parse_conflict_else_select(vec!["username"], "users::User");
+///
+///assert_eq!(stmt, " unless conflict on .username else ( select users::User ) ");
+/// ```
+pub fn parse_conflict_else_select(on_fields: Vec<&str>, table_name: &str) -> String {
+    let mut stmt = on_statement(on_fields);
+
+    stmt.push_str(ELSE);
+    stmt.push_str(format!("select {table_name}").as_str());
+    stmt.push_str(CLOSE_PARENTHESIS);
+
+    stmt
+}
+
+/// parse an upsert conflict into a string statement
+///
+/// __on_fields__ : the conflict key fields
+///
+/// __table_name__ : the upserted table name
+///
+/// __set_stmt__ : the set statement of the fields overwritten on conflict
+///
+/// ## Examples
+///
+/// ```
+///use edgedb_query::queries::conflict::parse_upsert;
+///
+///let stmt = parse_upsert(vec!["email"], "users::User", "name := (select <str>$name), ");
+///
+///assert_eq!(stmt, " unless conflict on .email else ( update users::User set { name := (select <str>$name), } ) ");
+/// ```
+pub fn parse_upsert(on_fields: Vec<&str>, table_name: &str, set_stmt: &str) -> String {
+    let mut stmt = on_statement(on_fields);
+
+    stmt.push_str(ELSE);
+    stmt.push_str(format!("update {table_name} set {{ {set_stmt}}}").as_str());
+    stmt.push_str(CLOSE_PARENTHESIS);
+
+    stmt
+}
+
+fn on_statement(on_fields: Vec<&str>) -> String {
     let mut stmt = UNLESS_CONFLICT.to_owned();
 
     if !on_fields.is_empty() {
@@ -116,12 +176,5 @@ pub fn parse_conflict<T: ToEdgeQuery + Clone, R: Conflict<T>>(conflict: &R, on_f
         }
     }
 
-    if let Some(else_query)= conflict.else_query() {
-        stmt.push_str(ELSE);
-        stmt.push_str(else_query.to_edgeql().to_string().as_str());
-        stmt.push_str(CLOSE_PARENTHESIS);
-    }
-
     stmt
 }
-
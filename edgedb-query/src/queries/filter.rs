@@ -7,4 +7,9 @@ pub trait Filter {
 
     /// build the args object
     fn to_edge_value(&self) -> edgedb_protocol::value::Value;
+
+    /// build the args object, failing when a value overflows its edgeDB type
+    fn try_to_edge_value(&self) -> Result<edgedb_protocol::value::Value, crate::ValueOverflow> {
+        Ok(self.to_edge_value())
+    }
 }
\ No newline at end of file
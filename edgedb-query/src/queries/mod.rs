@@ -2,3 +2,6 @@ pub mod select;
 pub mod filter;
 pub mod conflict;
 pub mod set;
+pub mod paged;
+pub mod bulk;
+pub mod version;
\ No newline at end of file
@@ -0,0 +1,124 @@
+use edgedb_protocol::descriptors::TypePos;
+use edgedb_protocol::errors::DecodeError;
+use edgedb_protocol::queryable::{Decoder, DescriptorContext, DescriptorMismatch, Queryable};
+use edgedb_protocol::value::Value;
+use crate::{EdgeQl, ToEdgeQl, ToEdgeQuery, ToEdgeValue, ValueOverflow};
+use crate::models::query_result::{check_field_descriptor, object_elements, open_object};
+
+const FILTERED: &str = "filtered";
+const TOTAL: &str = "total";
+const ITEMS: &str = "items";
+
+/// PagedQuery trait represents a select query with options that can be rendered
+/// as a page of items along with the total count of the matching items.
+///
+/// It is implemented by every `#[select_query]` struct having an `#[options]` field.
+pub trait PagedQuery: ToEdgeValue {
+    /// returns the paged query statement
+    fn paged_edgeql(&self) -> String;
+}
+
+/// Build the paged select statement
+///
+/// __table_name__ : the selected table name
+///
+/// __filters__ : the filter statement, shared by the total count and the items
+///
+/// __shape__ : the items shape
+///
+/// __options__ : the items order and pagination options
+///
+/// ## Examples
+///
+/// ```
+/// use edgedb_query::queries::paged::paged_statement;
+///
+/// let stmt = paged_statement("users::User", "filter users::User.age > 18", "{id,name}", " order by .name asc limit 10");
+///
+/// assert_eq!(stmt, "with filtered := (select users::User filter users::User.age > 18) select {total := count(filtered),items := (select filtered {id,name} order by .name asc limit 10)}");
+/// ```
+pub fn paged_statement(table_name: &str, filters: &str, shape: &str, options: &str) -> String {
+    let filtered = if filters.trim().is_empty() {
+        format!("(select {table_name})")
+    } else {
+        format!("(select {table_name} {})", filters.trim())
+    };
+
+    let items = if shape.is_empty() {
+        format!("select {FILTERED}{options}")
+    } else {
+        format!("select {FILTERED} {shape}{options}")
+    };
+
+    format!("with {FILTERED} := {filtered} select {{{TOTAL} := count({FILTERED}),{ITEMS} := ({items})}}")
+}
+
+/// Paged struct wraps a select query with options in order to fetch,
+/// in a single round trip, a page of items and the total count of the matching items.
+///
+/// The query result is a `Page<T>`, __T__ being the select query result
+///
+///<br>
+///
+/// ## Examples
+///
+/// ```rust,ignore
+///     let query = Paged(FindUsers { name: "%oe".to_owned(), options }).to_edge_query();
+///
+///     let page: Page<UserResult> = client.query_required_single(query.query.as_str(), &query.args.unwrap()).await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Paged<Q: PagedQuery>(pub Q);
+
+impl<Q: PagedQuery> ToEdgeQl for Paged<Q> {
+    fn to_edgeql(&self) -> EdgeQl {
+        EdgeQl::new(self.0.paged_edgeql(), false)
+    }
+}
+
+impl<Q: PagedQuery> ToEdgeValue for Paged<Q> {
+    fn to_edge_value(&self) -> Value {
+        self.0.to_edge_value()
+    }
+
+    fn try_to_edge_value(&self) -> Result<Value, ValueOverflow> {
+        self.0.try_to_edge_value()
+    }
+}
+
+impl<Q: PagedQuery> ToEdgeQuery for Paged<Q> {}
+
+/// Page struct represents the result of a paged query
+///
+/// __total__ : the total count of the items matching the query filters
+///
+/// __items__ : the items of the requested page
+#[derive(Debug, Clone, Default)]
+pub struct Page<T> {
+    pub total: i64,
+    pub items: Vec<T>,
+}
+
+impl<T: Queryable> Queryable for Page<T> {
+    fn decode(decoder: &Decoder, buf: &[u8]) -> Result<Self, DecodeError> {
+        let (mut elements, _) = open_object(decoder, buf, false)?;
+
+        let total = Queryable::decode_optional(decoder, elements.read()?)?;
+        let items = Queryable::decode_optional(decoder, elements.read()?)?;
+
+        Ok(Self { total, items })
+    }
+
+    fn check_descriptor(ctx: &DescriptorContext, type_pos: TypePos) -> Result<(), DescriptorMismatch> {
+        let (elements, mut idx) = object_elements(ctx, type_pos, false)?;
+
+        check_field_descriptor::<i64>(ctx, elements, &mut idx, TOTAL)?;
+        check_field_descriptor::<Vec<T>>(ctx, elements, &mut idx, ITEMS)?;
+
+        if elements.len() != idx {
+            return Err(ctx.field_number(elements.len(), idx));
+        }
+
+        Ok(())
+    }
+}
@@ -43,6 +43,41 @@ pub trait Options {
 ///
 /// ```
 pub fn parse_options<T: Options>(options: &T, table_name: impl Into<String>, result_fields: Vec<&str>) -> String {
+    if let Some(OrderOptions { order_by, .. }) = options.order_options() {
+        if !result_fields.contains(&order_by.as_str()) {
+            panic!("'order by' value must be one of {:#?}", result_fields)
+        }
+    }
+
+    parse_unchecked_options(options, table_name)
+}
+
+/// Parse the query options without checking that the 'order by' value is one of the result fields
+/// (e.g. for a delete query, whose objects can be ordered by any property)
+///
+/// __returns__ : the options statement
+///
+/// ## Examples
+///
+/// ```
+/// use edgedb_query::queries::select::{OrderOptions, parse_unchecked_options, SelectOptions, PageOptions};
+///
+/// let options = SelectOptions {
+///          order_options: Some(OrderOptions {
+///              order_by: String::from("created_at"),
+///              order_direction: None,
+///          }),
+///          page_options: Some(PageOptions {
+///              limit: 100,
+///              offset: None
+///          })
+///      };
+///  let stmt = parse_unchecked_options(&options, "logs::Event");
+///
+///  assert_eq!(" order by logs::Event.created_at asc limit 100".to_owned(), stmt)
+///
+/// ```
+pub fn parse_unchecked_options<T: Options>(options: &T, table_name: impl Into<String>) -> String {
 
     let mut stmt = String::default();
 
@@ -53,10 +88,6 @@ pub fn parse_options<T: Options>(options: &T, table_name: impl Into<String>, res
                     order_direction,
                 }) = options.order_options().clone()
     {
-        if !result_fields.contains(&order_by.as_str()) {
-            panic!("'order by' value must be one of {:#?}", result_fields)
-        }
-
         stmt.push_str(format!(" {} {}.{}", ORDER_BY, table_name, order_by).as_str());
 
         if let Some(OrderDir::Desc) = order_direction {
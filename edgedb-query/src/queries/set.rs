@@ -4,4 +4,29 @@ pub trait Sets {
     fn to_edgeql(&self) -> String;
     fn nested_edgeqls(&self) -> Vec<EdgeQl>;
     fn to_edge_value(&self) -> edgedb_protocol::value::Value;
+
+    /// build the args object, failing when a value overflows its edgeDB type
+    fn try_to_edge_value(&self) -> Result<edgedb_protocol::value::Value, crate::ValueOverflow> {
+        Ok(self.to_edge_value())
+    }
+}
+
+/// Check whether a set statement has no assignment, all of its optional fields being skipped
+///
+/// ## Examples
+///
+/// ```
+/// use edgedb_query::queries::set::is_empty_set;
+///
+/// assert!(is_empty_set("set {  }"));
+/// assert!(!is_empty_set("set { name := (select <str>$name) }"));
+/// ```
+pub fn is_empty_set(set_stmt: &str) -> bool {
+    set_stmt.trim()
+        .trim_start_matches("set")
+        .trim()
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .trim()
+        .is_empty()
 }
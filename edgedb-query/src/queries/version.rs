@@ -0,0 +1,102 @@
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+
+use crate::models::edge_query::{EdgeQuery, ToEdgeQuery};
+
+/// StaleVersion error : no object has been updated because its version
+/// did not match the expected one (it has been updated concurrently)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleVersion {
+    pub expected_version: i64,
+}
+
+impl Display for StaleVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Stale version : no object found with version {}", self.expected_version)
+    }
+}
+
+impl std::error::Error for StaleVersion {}
+
+/// VersionedUpdate trait represents an update query checking the version of the updated objects.
+///
+/// It is implemented by every `#[update_query]` struct with a __version__ attribute.
+pub trait VersionedUpdate {
+    /// returns the version the updated objects are expected to have
+    fn expected_version(&self) -> i64;
+
+    /// Map an empty list of updated objects to a StaleVersion error
+    fn check_updated<R>(&self, updated: Vec<R>) -> Result<Vec<R>, StaleVersion> {
+        if updated.is_empty() {
+            Err(StaleVersion { expected_version: self.expected_version() })
+        } else {
+            Ok(updated)
+        }
+    }
+
+    /// Map a missing updated object to a StaleVersion error
+    fn check_updated_single<R>(&self, updated: Option<R>) -> Result<R, StaleVersion> {
+        updated.ok_or(StaleVersion { expected_version: self.expected_version() })
+    }
+
+    /// Execute the update query with __run__ (e.g. a client query call)
+    /// and map an empty list of updated objects to a StaleVersion error
+    ///
+    /// ## Examples
+    ///
+    /// ```rust,ignore
+    ///     let updated: Vec<BasicResult> = update_user_name.execute(|q| async move {
+    ///         client.query(q.query.as_str(), &q.args.unwrap()).await.map_err(anyhow::Error::from)
+    ///     }).await?;
+    /// ```
+    fn execute<R, E, F, Fut>(&self, run: F) -> impl Future<Output = Result<Vec<R>, E>>
+    where
+        Self: ToEdgeQuery,
+        F: FnOnce(EdgeQuery) -> Fut,
+        Fut: Future<Output = Result<Vec<R>, E>>,
+        E: From<StaleVersion>,
+    {
+        let expected_version = self.expected_version();
+        let updated = run(self.to_edge_query());
+
+        async move {
+            let updated = updated.await?;
+
+            if updated.is_empty() {
+                Err(StaleVersion { expected_version }.into())
+            } else {
+                Ok(updated)
+            }
+        }
+    }
+}
+
+/// Add the version increment to a set statement
+///
+/// ## Examples
+///
+/// ```
+/// use edgedb_query::queries::version::version_set;
+///
+/// assert_eq!(
+///     version_set("set { name := (select <str>$name), nickname := {} }", "version"),
+///     "set { name := (select <str>$name), nickname := {}, version := .version + 1 }"
+/// );
+/// ```
+pub fn version_set(set_stmt: &str, version: &str) -> String {
+    let set_stmt = set_stmt.trim();
+    let set_stmt = set_stmt.strip_prefix("set").unwrap_or(set_stmt).trim();
+
+    let assignments = set_stmt.strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .unwrap_or(set_stmt)
+        .trim()
+        .trim_end_matches(',')
+        .trim();
+
+    if assignments.is_empty() {
+        format!("set {{ {version} := .{version} + 1 }}")
+    } else {
+        format!("set {{ {assignments}, {version} := .{version} + 1 }}")
+    }
+}
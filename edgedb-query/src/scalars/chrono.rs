@@ -0,0 +1,47 @@
+use chrono::Datelike;
+use edgedb_protocol::model::{Duration, LocalTime};
+use edgedb_protocol::value::Value;
+use crate::{ToEdgeValue, ValueOverflow};
+use crate::scalars::edge_time::{datetime, local_date, unix_local_datetime};
+
+/// number of days from 0001-01-01 to the edgeDB epoch (2000-01-01)
+const EPOCH_DAYS_FROM_CE: i64 = 730_120;
+
+_checked_to_edge_value_impls!(
+    chrono::DateTime<chrono::Utc> => {
+        scalar: "datetime",
+        value: |v| datetime(v.timestamp_micros() as i128).map(Value::Datetime)
+    },
+    chrono::DateTime<chrono::Local> => {
+        scalar: "datetime",
+        value: |v| datetime(v.timestamp_micros() as i128).map(Value::Datetime)
+    },
+    chrono::NaiveDateTime => {
+        scalar: "cal::local_datetime",
+        value: |v| unix_local_datetime(v.and_utc().timestamp_micros() as i128).map(Value::LocalDatetime)
+    },
+    chrono::NaiveDate => {
+        scalar: "cal::local_date",
+        value: |v| local_date(v.num_days_from_ce() as i64 - EPOCH_DAYS_FROM_CE).map(Value::LocalDate)
+    },
+    chrono::NaiveTime => {
+        scalar: "cal::local_time",
+        value: |v| Some(Value::LocalTime(LocalTime::from(*v)))
+    },
+    chrono::Duration => {
+        scalar: "duration",
+        value: |v| v.num_microseconds().map(|micros| Value::Duration(Duration::from_micros(micros)))
+    },
+);
+
+#[allow(deprecated)]
+impl ToEdgeValue for chrono::Date<chrono::Local> {
+    /// ⚠️ panics when the value is out of the edgeDB type range, use __try_to_edge_value__ to handle the overflow
+    fn to_edge_value(&self) -> Value {
+        self.try_to_edge_value().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn try_to_edge_value(&self) -> Result<Value, ValueOverflow> {
+        self.naive_local().try_to_edge_value()
+    }
+}
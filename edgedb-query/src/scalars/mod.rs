@@ -0,0 +1,63 @@
+/// Implement ToEdgeValue for a date or time type, given its conversion into an edgedb_protocol value
+///
+/// The conversion fails (returns None) when the value is out of the edgeDB type range
+macro_rules! _checked_to_edge_value_impls {
+    ($($ty: ty => { scalar: $scalar: expr, value: $value: expr }),* $(,)?) => {
+        $(
+            impl ToEdgeValue for $ty {
+                /// ⚠️ panics when the value is out of the edgeDB type range, use __try_to_edge_value__ to handle the overflow
+                fn to_edge_value(&self) -> Value {
+                    self.try_to_edge_value().unwrap_or_else(|e| panic!("{e}"))
+                }
+
+                fn try_to_edge_value(&self) -> Result<Value, $crate::ValueOverflow> {
+                    let value: fn(&$ty) -> Option<Value> = $value;
+                    value(self).ok_or_else(|| $crate::ValueOverflow::new(self, $scalar))
+                }
+            }
+        )*
+    }
+}
+
+/// Conversions into the edgedb_protocol date and time values, checked against the edgeDB ranges
+///
+/// edgeDB dates and times are relative to the 2000-01-01 epoch
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod edge_time {
+    use edgedb_protocol::model::{Datetime, LocalDate, LocalDatetime};
+
+    #[cfg(feature = "time")]
+    pub const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+    /// returns the local date __days__ after the epoch
+    pub fn local_date(days: i64) -> Option<LocalDate> {
+        (LocalDate::MIN.to_days() as i64..=LocalDate::MAX.to_days() as i64)
+            .contains(&days)
+            .then(|| LocalDate::from_days(days as i32))
+    }
+
+    /// returns the local datetime __micros__ after the epoch
+    pub fn local_datetime(micros: i64) -> Option<LocalDatetime> {
+        (LocalDatetime::MIN.to_micros()..=LocalDatetime::MAX.to_micros())
+            .contains(&micros)
+            .then(|| LocalDatetime::from_micros(micros))
+    }
+
+    /// returns the datetime __unix_micros__ after the unix epoch
+    pub fn datetime(unix_micros: i128) -> Option<Datetime> {
+        i64::try_from(unix_micros + Datetime::UNIX_EPOCH.to_micros() as i128).ok()
+            .and_then(|micros| Datetime::try_from_micros(micros).ok())
+    }
+
+    /// returns the local datetime __unix_micros__ after the unix epoch
+    #[cfg(feature = "chrono")]
+    pub fn unix_local_datetime(unix_micros: i128) -> Option<LocalDatetime> {
+        i64::try_from(unix_micros + Datetime::UNIX_EPOCH.to_micros() as i128).ok()
+            .and_then(local_datetime)
+    }
+}
+
+#[cfg(feature = "chrono")]
+pub mod chrono;
+#[cfg(feature = "time")]
+pub mod time;
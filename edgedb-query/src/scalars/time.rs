@@ -0,0 +1,41 @@
+use edgedb_protocol::model::{Duration, LocalTime};
+use edgedb_protocol::value::Value;
+use crate::ToEdgeValue;
+use crate::scalars::edge_time::{datetime, local_date, local_datetime, MICROS_PER_DAY};
+
+/// julian day of the edgeDB epoch (2000-01-01)
+const EPOCH_JULIAN_DAY: i64 = 2_451_545;
+
+/// returns the number of days from the edgeDB epoch to __date__
+fn epoch_days(date: &time::Date) -> i64 {
+    date.to_julian_day() as i64 - EPOCH_JULIAN_DAY
+}
+
+fn time_micros(time: &time::Time) -> i64 {
+    let (hour, minute, second, micro) = time.as_hms_micro();
+
+    ((hour as i64 * 60 + minute as i64) * 60 + second as i64) * 1_000_000 + micro as i64
+}
+
+_checked_to_edge_value_impls!(
+    time::OffsetDateTime => {
+        scalar: "datetime",
+        value: |v| datetime(v.unix_timestamp_nanos().div_euclid(1_000)).map(Value::Datetime)
+    },
+    time::PrimitiveDateTime => {
+        scalar: "cal::local_datetime",
+        value: |v| local_datetime(epoch_days(&v.date()) * MICROS_PER_DAY + time_micros(&v.time())).map(Value::LocalDatetime)
+    },
+    time::Date => {
+        scalar: "cal::local_date",
+        value: |v| local_date(epoch_days(v)).map(Value::LocalDate)
+    },
+    time::Time => {
+        scalar: "cal::local_time",
+        value: |v| Some(Value::LocalTime(LocalTime::from_micros(time_micros(v) as u64)))
+    },
+    time::Duration => {
+        scalar: "duration",
+        value: |v| i64::try_from(v.whole_microseconds()).ok().map(|micros| Value::Duration(Duration::from_micros(micros)))
+    },
+);
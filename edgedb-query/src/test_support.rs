@@ -0,0 +1,103 @@
+//! Test support : helpers shared by the edgedb-query and edgedb-query-derive tests, not part of the public api
+
+use bytes::Bytes;
+use edgedb_protocol::codec::STD_UUID;
+use edgedb_protocol::descriptors::{OutputTypedesc, TypePos};
+use edgedb_protocol::encoding::Input;
+use edgedb_protocol::features::ProtocolVersion;
+use uuid::Uuid;
+
+/// Builds the output type descriptors sent by the server, the last added descriptor is the root one
+#[derive(Default)]
+pub struct TypeDescBuilder {
+    buf: Vec<u8>,
+    ids: Vec<Uuid>,
+}
+
+impl TypeDescBuilder {
+    pub fn base_scalar(&mut self, id: Uuid) -> TypePos {
+        if let Some(pos) = self.ids.iter().position(|i| *i == id) {
+            return TypePos(pos as u16);
+        }
+        self.descriptor(2, id, vec![])
+    }
+
+    /// Add an object shape, whose elements are required, preceded by an implicit id when __implicit_id__ is true
+    pub fn object_shape(&mut self, implicit_id: bool, elements: &[(&str, TypePos)]) -> TypePos {
+        let mut body = vec![];
+        let mut count = elements.len() as u16;
+
+        if implicit_id {
+            let uuid_pos = self.base_scalar(STD_UUID);
+            count += 1;
+            shape_element(&mut body, 0b001, "id", uuid_pos);
+        }
+
+        elements.iter().for_each(|(name, pos)| shape_element(&mut body, 0, name, *pos));
+
+        let mut desc = count.to_be_bytes().to_vec();
+        desc.extend(body);
+        self.descriptor(1, self.next_id(), desc)
+    }
+
+    pub fn tuple(&mut self, elements: &[TypePos]) -> TypePos {
+        let mut body = (elements.len() as u16).to_be_bytes().to_vec();
+        elements.iter().for_each(|pos| body.extend(pos.0.to_be_bytes()));
+        self.descriptor(4, self.next_id(), body)
+    }
+
+    pub fn named_tuple(&mut self, elements: &[(&str, TypePos)]) -> TypePos {
+        let mut body = (elements.len() as u16).to_be_bytes().to_vec();
+        elements.iter().for_each(|(name, pos)| {
+            body.extend((name.len() as u32).to_be_bytes());
+            body.extend(name.as_bytes());
+            body.extend(pos.0.to_be_bytes());
+        });
+        self.descriptor(5, self.next_id(), body)
+    }
+
+    pub fn scalar(&mut self, base: TypePos) -> TypePos {
+        self.descriptor(3, self.next_id(), base.0.to_be_bytes().to_vec())
+    }
+
+    pub fn range(&mut self, element: TypePos) -> TypePos {
+        self.descriptor(9, self.next_id(), element.0.to_be_bytes().to_vec())
+    }
+
+    pub fn set(&mut self, element: TypePos) -> TypePos {
+        self.descriptor(0, self.next_id(), element.0.to_be_bytes().to_vec())
+    }
+
+    pub fn array(&mut self, element: TypePos) -> TypePos {
+        let mut body = element.0.to_be_bytes().to_vec();
+        body.extend(1_u16.to_be_bytes());
+        body.extend((-1_i32).to_be_bytes());
+        self.descriptor(6, self.next_id(), body)
+    }
+
+    fn next_id(&self) -> Uuid {
+        Uuid::from_u128(0x1000 + self.ids.len() as u128)
+    }
+
+    fn descriptor(&mut self, tag: u8, id: Uuid, body: Vec<u8>) -> TypePos {
+        self.buf.push(tag);
+        self.buf.extend_from_slice(id.as_bytes());
+        self.buf.extend(body);
+        self.ids.push(id);
+        TypePos(self.ids.len() as u16 - 1)
+    }
+
+    pub fn build(self) -> OutputTypedesc {
+        let root_id = *self.ids.last().expect("no descriptor");
+        OutputTypedesc::decode_with_id(root_id, &mut Input::new(ProtocolVersion::current(), Bytes::from(self.buf)))
+            .expect("invalid descriptors")
+    }
+}
+
+fn shape_element(body: &mut Vec<u8>, flags: u32, name: &str, pos: TypePos) {
+    body.extend(flags.to_be_bytes());
+    body.push(0x41);
+    body.extend((name.len() as u32).to_be_bytes());
+    body.extend(name.as_bytes());
+    body.extend(pos.0.to_be_bytes());
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod dynamic_shape_tests {
+    use edgedb_protocol::codec::{scalar_codec, ObjectShape, STD_DECIMAL};
+    use edgedb_protocol::common::Cardinality;
+    use edgedb_protocol::descriptors::{ShapeElement, TypePos};
+    use edgedb_protocol::value::Value;
+    use edgedb_query::{DynamicShape, EdgeResult, ToEdgeShape};
+    use edgedb_protocol::model::{BigInt, ConfigMemory, Datetime, LocalDatetime, Range};
+    use edgedb_query::models::dynamic_shape::{to_json, DynamicShapeError};
+    use serde::Deserialize;
+
+    #[derive(Debug, Clone)]
+    struct UserResult;
+
+    impl ToEdgeShape for UserResult {
+        fn shape() -> String {
+            "{id,name,login := (select <str>str_upper(.pseudo)),friend := (select users::User.<friend[is users::Friend]{id,surname} limit 1)}".to_owned()
+        }
+    }
+
+    impl EdgeResult for UserResult {
+        fn returning_fields() -> Vec<&'static str> {
+            vec!["id", "name", "login", "friend"]
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct PartialUser {
+        name: Option<String>,
+        login: Option<String>,
+    }
+
+    fn element(name: &str) -> ShapeElement {
+        ShapeElement {
+            flag_implicit: false,
+            flag_link_property: false,
+            flag_link: false,
+            cardinality: Some(Cardinality::One),
+            name: name.to_string(),
+            type_pos: TypePos(0),
+        }
+    }
+
+    #[test]
+    fn shape_with_selected_fields() {
+        let shape = DynamicShape::<UserResult>::parse("friend, login").unwrap();
+
+        assert_eq!(shape.fields(), vec!["friend", "login"]);
+        assert_eq!(shape.shape(), "{login := (select <str>str_upper(.pseudo)),friend := (select users::User.<friend[is users::Friend]{id,surname} limit 1)}");
+    }
+
+    #[test]
+    fn shape_with_all_fields() {
+        let shape = DynamicShape::<UserResult>::all();
+
+        assert_eq!(shape.shape(), UserResult::shape());
+    }
+
+    #[test]
+    fn shape_with_unknown_field() {
+        let shape = DynamicShape::<UserResult>::new(&["name", "password"]);
+
+        assert_eq!(shape.unwrap_err(), DynamicShapeError::UnknownField("password".to_string()));
+    }
+
+    #[test]
+    fn shape_without_field() {
+        let shape = DynamicShape::<UserResult>::parse("");
+
+        assert_eq!(shape.unwrap_err(), DynamicShapeError::NoField);
+    }
+
+    #[test]
+    fn decode_into_optional_fields() {
+        let shape = DynamicShape::<UserResult>::parse("name").unwrap();
+
+        let elements: &[ShapeElement] = &[element("name")];
+
+        let value = Value::Object {
+            shape: ObjectShape::from(elements),
+            fields: vec![Some(Value::Str("Joe".to_string()))],
+        };
+
+        let user: PartialUser = shape.decode(&value).unwrap();
+
+        assert_eq!(user.name, Some("Joe".to_string()));
+        assert_eq!(user.login, None);
+        assert_eq!(shape.decode_json(&value), Ok(serde_json::json!({"name": "Joe"})));
+    }
+
+    fn decimal(wire: &[u16]) -> Value {
+        let buf = wire.iter().flat_map(|w| w.to_be_bytes()).collect::<Vec<u8>>();
+        scalar_codec(&STD_DECIMAL).unwrap().decode(&buf).unwrap()
+    }
+
+    #[test]
+    fn scalars_to_json() {
+        assert_eq!(to_json(&Value::BigInt(BigInt::from(-12_345_678_901_i64))), Ok(serde_json::json!("-12345678901")));
+        assert_eq!(to_json(&decimal(&[2, 0, 0x4000, 2, 12, 5000])), Ok(serde_json::json!("-12.50")));
+        assert_eq!(to_json(&decimal(&[1, 0xffff, 0, 4, 12])), Ok(serde_json::json!("0.0012")));
+        assert_eq!(to_json(&decimal(&[2, 1, 0, 0, 1, 0])), Ok(serde_json::json!("10000")));
+        assert_eq!(to_json(&Value::ConfigMemory(ConfigMemory(2048))), Ok(serde_json::json!("2KiB")));
+        assert_eq!(to_json(&Value::Datetime(Datetime::from_micros(86_400_000_001))), Ok(serde_json::json!("2000-01-02T00:00:00.000001Z")));
+        assert_eq!(to_json(&Value::LocalDatetime(LocalDatetime::from_micros(-1))), Ok(serde_json::json!("1999-12-31T23:59:59.999999")));
+    }
+
+    #[test]
+    fn range_to_json() {
+        let range = Value::Range(Range::from(Box::new(Value::Int64(1))..Box::new(Value::Int64(5))));
+
+        assert_eq!(to_json(&range), Ok(serde_json::json!({"lower": 1, "upper": 5, "inc_lower": true, "inc_upper": false})));
+        assert_eq!(to_json(&Value::Range(Range::empty())), Ok(serde_json::json!({"empty": true})));
+    }
+}
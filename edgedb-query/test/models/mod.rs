@@ -0,0 +1,3 @@
+mod dynamic_shape;
+mod range;
+mod tuple;
@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod range_tests {
+    use edgedb_protocol::codec::{STD_INT64, STD_STR};
+    use edgedb_protocol::model::Range as EdgeRange;
+    use edgedb_protocol::queryable::{Decoder, Queryable};
+    use edgedb_protocol::value::Value;
+    use edgedb_query::{Range, ToEdgeScalar, ToEdgeValue, ValueOverflow};
+    use edgedb_query::test_support::TypeDescBuilder;
+
+    fn bounds(value: Value) -> (Option<Value>, Option<Value>, bool, bool) {
+        if let Value::Range(range) = value {
+            (range.lower().map(|v| *v.clone()), range.upper().map(|v| *v.clone()), range.inc_lower(), range.inc_upper())
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn int64_bound(value: i64) -> Vec<u8> {
+        let mut buf = 8_i32.to_be_bytes().to_vec();
+        buf.extend_from_slice(&value.to_be_bytes());
+        buf
+    }
+
+    #[test]
+    fn range_scalar_test() {
+        assert_eq!(Range::<i32>::scalar(), "<range<int32>>");
+        assert_eq!(Range::<i64>::scalar(), "<range<int64>>");
+        assert_eq!(Range::<f64>::scalar(), "<range<float64>>");
+    }
+
+    #[test]
+    fn std_ranges_test() {
+        assert_eq!(Range::from(1..5), Range::new(Some(1), Some(5)));
+        assert_eq!(Range::from(1..=5), Range::inclusive(1, 5));
+        assert_eq!(Range::from(1..), Range::new(Some(1), None));
+        assert_eq!(Range::from(..5), Range::new(None, Some(5)));
+    }
+
+    #[test]
+    fn range_value_test() {
+        let expected = EdgeRange::from(Box::new(Value::Int64(1))..Box::new(Value::Int64(5)));
+
+        assert_eq!(Range::<i64>::from(1..5).to_edge_value(), Value::Range(expected));
+    }
+
+    #[test]
+    fn unbounded_range_value_test() {
+        assert_eq!(bounds(Range::<i64>::from(..5).to_edge_value()), (None, Some(Value::Int64(5)), true, false));
+        assert_eq!(bounds(Range::<i64>::from(1..).to_edge_value()), (Some(Value::Int64(1)), None, true, false));
+    }
+
+    #[test]
+    fn inclusive_range_value_test() {
+        assert_eq!(bounds(Range::<i64>::from(1..=5).to_edge_value()), (Some(Value::Int64(1)), Some(Value::Int64(5)), true, true));
+        assert_eq!(bounds(Range::inclusive(1.5_f64, 2.5).to_edge_value()), (Some(Value::Float64(1.5)), Some(Value::Float64(2.5)), true, true));
+    }
+
+    #[test]
+    fn empty_range_value_test() {
+        assert_eq!(Range::<i64>::empty().to_edge_value(), Value::Range(EdgeRange::empty()));
+    }
+
+    #[test]
+    fn range_overflow_test() {
+        let range = Range::from(0..u64::MAX);
+
+        assert_eq!(range.try_to_edge_value(), Err(ValueOverflow::new(u64::MAX, "int64")));
+    }
+
+    #[test]
+    fn invalid_range_bound_test() {
+        let range = Range::inclusive("a".to_owned(), "b".to_owned());
+
+        assert_eq!(range.try_to_edge_value(), Err(ValueOverflow::new(r#"Str("a")"#, "range")));
+    }
+
+    #[test]
+    fn mismatched_range_bounds_test() {
+        let range = edgedb_query::models::range::range_value(Some(Value::Int32(1)), Some(Value::Int64(5)), true, true);
+
+        assert_eq!(range, Err(ValueOverflow::new("Int64(5)", "range")));
+    }
+
+    #[test]
+    fn from_edge_range_test() {
+        assert_eq!(Range::from(EdgeRange::from(1_i64..5)), Range::from(1..5));
+        assert_eq!(Range::from(EdgeRange::<i64>::empty()), Range::empty());
+    }
+
+    #[test]
+    fn decode_range_test() {
+        let mut buf = vec![0x02 | 0x04];
+        buf.extend(int64_bound(1));
+        buf.extend(int64_bound(5));
+
+        assert_eq!(Range::<i64>::decode(&Decoder::default(), &buf).unwrap(), Range::inclusive(1, 5));
+
+        let mut buf = vec![0x02 | 0x10];
+        buf.extend(int64_bound(1));
+
+        assert_eq!(Range::<i64>::decode(&Decoder::default(), &buf).unwrap(), Range::from(1..));
+
+        assert_eq!(Range::<i64>::decode(&Decoder::default(), &[0x01]).unwrap(), Range::empty());
+    }
+
+    #[test]
+    fn decode_invalid_range_test() {
+        let mut buf = vec![0x02 | 0x10];
+        buf.extend(int64_bound(1));
+        buf.push(0);
+
+        assert!(Range::<i64>::decode(&Decoder::default(), &buf).is_err());
+        assert!(Range::<i64>::decode(&Decoder::default(), &[0x02, 0, 0, 0, 8]).is_err());
+    }
+
+    #[test]
+    fn check_range_descriptor_test() {
+        let mut builder = TypeDescBuilder::default();
+        let element = builder.base_scalar(STD_INT64);
+        builder.range(element);
+        let desc = builder.build();
+
+        assert!(Range::<i64>::check_descriptor(&desc.as_queryable_context(), desc.root_pos().unwrap()).is_ok());
+        assert!(Range::<String>::check_descriptor(&desc.as_queryable_context(), desc.root_pos().unwrap()).is_err());
+
+        let mut builder = TypeDescBuilder::default();
+        builder.base_scalar(STD_STR);
+        let desc = builder.build();
+
+        assert!(Range::<String>::check_descriptor(&desc.as_queryable_context(), desc.root_pos().unwrap()).is_err());
+    }
+}
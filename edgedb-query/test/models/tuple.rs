@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tuple_tests {
+    use edgedb_protocol::value::Value;
+    use edgedb_query::{scalar_name, ToEdgeScalar, ToEdgeValue, ValueOverflow};
+
+    #[test]
+    fn scalar_name_test() {
+        assert_eq!(scalar_name::<i64>(), "int64");
+        assert_eq!(scalar_name::<Vec<String>>(), "array<str>");
+    }
+
+    #[test]
+    fn tuple_scalar_test() {
+        assert_eq!(<(i64, String)>::scalar(), "<tuple<int64, str>>");
+        assert_eq!(<(bool, f64, Vec<i16>)>::scalar(), "<tuple<bool, float64, array<int16>>>");
+    }
+
+    #[test]
+    fn tuple_value_test() {
+        assert_eq!(
+            (1_i32, "a".to_owned(), true).to_edge_value(),
+            Value::Tuple(vec![Value::Int32(1), Value::Str("a".to_owned()), Value::Bool(true)])
+        );
+    }
+
+    #[test]
+    fn tuple_overflow_test() {
+        assert_eq!((1_i32, u64::MAX).try_to_edge_value(), Err(ValueOverflow::new(u64::MAX, "int64")));
+    }
+}
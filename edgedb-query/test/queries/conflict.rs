@@ -2,7 +2,7 @@
 #[cfg(test)]
 mod conflict_test {
     use edgedb_protocol::value::Value;
-    use edgedb_query::queries::conflict::{UnlessConflict, UnlessConflictElse, parse_conflict};
+    use edgedb_query::queries::conflict::{UnlessConflict, UnlessConflictElse, parse_conflict, parse_conflict_else_select};
     use edgedb_query::{EdgeQl, QueryType, ToEdgeQl, ToEdgeQuery, ToEdgeValue};
 
     #[derive(Clone)]
@@ -84,4 +84,12 @@ mod conflict_test {
 
         assert_eq!(stmt, " unless conflict ");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn parse_conflict_with_else_select() {
+
+        let stmt = parse_conflict_else_select(vec!["name", "age"], "users::User");
+
+        assert_eq!(stmt, " unless conflict on ( .name, .age ) else ( select users::User ) ");
+    }
+}
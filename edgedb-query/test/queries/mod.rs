@@ -1,2 +1,4 @@
 mod select;
-mod conflict;
\ No newline at end of file
+mod conflict;
+mod paged;
+mod version;
\ No newline at end of file
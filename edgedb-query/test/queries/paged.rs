@@ -0,0 +1,18 @@
+#[cfg(test)]
+mod paged_tests {
+    use edgedb_query::queries::paged::paged_statement;
+
+    #[test]
+    fn paged_statement_without_filters() {
+        let stmt = paged_statement("users::User", "", "{id,name}", " limit 10");
+
+        assert_eq!(stmt, "with filtered := (select users::User) select {total := count(filtered),items := (select filtered {id,name} limit 10)}");
+    }
+
+    #[test]
+    fn paged_statement_with_filters_and_options() {
+        let stmt = paged_statement("users::User", "filter users::User.age >= (select <int16>$age)", "{id,name}", " order by .name asc limit 10 offset 20");
+
+        assert_eq!(stmt, "with filtered := (select users::User filter users::User.age >= (select <int16>$age)) select {total := count(filtered),items := (select filtered {id,name} order by .name asc limit 10 offset 20)}");
+    }
+}
@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod version_test {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+    use edgedb_protocol::value::Value;
+    use edgedb_query::{EdgeQl, QueryType, StaleVersion, ToEdgeQl, ToEdgeQuery, ToEdgeValue};
+    use edgedb_query::queries::version::{version_set, VersionedUpdate};
+
+    pub struct UpdateUser {
+        pub expected_version: i64,
+    }
+
+    impl VersionedUpdate for UpdateUser {
+        fn expected_version(&self) -> i64 {
+            self.expected_version
+        }
+    }
+
+    impl ToEdgeQl for UpdateUser {
+        fn to_edgeql(&self) -> EdgeQl {
+            EdgeQl {
+                query_type: QueryType::Update,
+                table_name: "users::User".to_owned(),
+                content: "filter users::User.version = (select <int64>$expected_version) set { version := .version + 1 }".to_owned(),
+                has_result: false,
+            }
+        }
+    }
+
+    impl ToEdgeValue for UpdateUser {
+        fn to_edge_value(&self) -> Value {
+            Value::Int64(self.expected_version)
+        }
+    }
+
+    impl ToEdgeQuery for UpdateUser {}
+
+    const NOOP_WAKER: RawWakerVTable = RawWakerVTable::new(|_| RawWaker::new(std::ptr::null(), &NOOP_WAKER), |_| {}, |_| {}, |_| {});
+
+    fn ready<T>(future: impl Future<Output = T>) -> T {
+        // the futures under test never wait : they are polled once with a waker doing nothing
+        let waker = unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &NOOP_WAKER)) };
+
+        match pin!(future).poll(&mut Context::from_waker(&waker)) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("future is not ready"),
+        }
+    }
+
+    #[test]
+    fn version_set_test() {
+        assert_eq!(version_set("set {  }", "version"), "set { version := .version + 1 }");
+    }
+
+    #[test]
+    fn check_updated_test() {
+        let update = UpdateUser { expected_version: 3 };
+
+        assert_eq!(update.check_updated(vec![1]), Ok(vec![1]));
+        assert_eq!(update.check_updated::<i32>(vec![]), Err(StaleVersion { expected_version: 3 }));
+        assert_eq!(update.check_updated_single(Some(1)), Ok(1));
+        assert_eq!(update.check_updated_single::<i32>(None), Err(StaleVersion { expected_version: 3 }));
+    }
+
+    #[test]
+    fn execute_test() {
+        let update = UpdateUser { expected_version: 3 };
+
+        let updated = ready(update.execute(|q| async move {
+            assert!(q.query.starts_with("update users::User filter"));
+            Ok::<_, StaleVersion>(vec![1])
+        }));
+        assert_eq!(updated, Ok(vec![1]));
+
+        let updated = ready(update.execute(|_| async { Ok::<Vec<i32>, StaleVersion>(vec![]) }));
+        assert_eq!(updated, Err(StaleVersion { expected_version: 3 }));
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod bignum_tests {
+    use edgedb_protocol::model::{BigInt, Decimal};
+    use edgedb_protocol::value::Value;
+    use edgedb_query::{ToEdgeScalar, ToEdgeValue};
+
+    #[test]
+    fn bigint_test() {
+        assert_eq!(BigInt::scalar(), "<bigint>");
+        assert_eq!(BigInt::from(42i64).to_edge_value(), Value::BigInt(BigInt::from(42i64)));
+    }
+
+    #[test]
+    fn decimal_test() {
+        assert_eq!(Decimal::scalar(), "<decimal>");
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn num_bigint_test() {
+        let value = num_bigint::BigInt::from(-1_000_000_000_000_i64);
+
+        assert_eq!(num_bigint::BigInt::scalar(), "<bigint>");
+        assert_eq!(value.to_edge_value(), Value::BigInt(BigInt::from(-1_000_000_000_000_i64)));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn rust_decimal_test() {
+        let value = rust_decimal::Decimal::new(12_050, 3);
+
+        assert_eq!(rust_decimal::Decimal::scalar(), "<decimal>");
+        assert!(matches!(value.to_edge_value(), Value::Decimal(_)));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn rust_decimal_result_test() {
+        use edgedb_protocol::codec::{scalar_codec, STD_DECIMAL};
+        use edgedb_protocol::queryable::{Decoder, Queryable};
+        use edgedb_query::RustDecimal;
+
+        let value = rust_decimal::Decimal::new(-12_050, 3);
+        let mut buf = bytes::BytesMut::new();
+        scalar_codec(&STD_DECIMAL).unwrap().encode(&mut buf, &value.to_edge_value()).unwrap();
+
+        assert_eq!(RustDecimal::scalar(), "<decimal>");
+        assert_eq!(RustDecimal::decode(&Decoder::default(), &buf).unwrap(), RustDecimal(value));
+
+        // 10^40 overflows rust_decimal::Decimal
+        let buf = [1_u16, 10, 0, 0, 1].iter().flat_map(|w| w.to_be_bytes()).collect::<Vec<u8>>();
+
+        assert!(RustDecimal::decode(&Decoder::default(), &buf).is_err());
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod bytes_tests {
+    use edgedb_protocol::codec::{STD_BYTES, STD_STR};
+    use edgedb_protocol::queryable::{Decoder, Queryable};
+    use edgedb_protocol::value::Value;
+    use edgedb_query::{Bytes, ToEdgeScalar, ToEdgeValue};
+    use edgedb_query::test_support::TypeDescBuilder;
+
+    #[test]
+    fn vec_u8_test() {
+        assert_eq!(Vec::<u8>::scalar(), "<bytes>");
+        assert_eq!(vec![0x00_u8, 0x7f, 0xff].to_edge_value(), Value::Bytes(vec![0x00, 0x7f, 0xff]));
+    }
+
+    #[test]
+    fn vec_of_other_ints_test() {
+        assert_eq!(Vec::<i16>::scalar(), "<array<int16>>");
+        assert_eq!(vec![1_i16, 2].to_edge_value(), Value::Array(vec![Value::Int16(1), Value::Int16(2)]));
+    }
+
+    #[test]
+    fn u8_test() {
+        assert_eq!(u8::scalar(), "<int16>");
+        assert_eq!(255_u8.to_edge_value(), Value::Int16(255));
+    }
+
+    #[test]
+    fn bytes_test() {
+        let bytes = Bytes::from(vec![0xca, 0xfe]);
+
+        assert_eq!(Bytes::scalar(), "<bytes>");
+        assert_eq!(bytes.to_edge_value(), Value::Bytes(vec![0xca, 0xfe]));
+        assert_eq!(Vec::<u8>::from(bytes), vec![0xca, 0xfe]);
+    }
+
+    #[test]
+    fn decode_bytes_test() {
+        let bytes = Bytes::decode(&Decoder::default(), &[0xca, 0xfe]).unwrap();
+
+        assert_eq!(bytes, Bytes(vec![0xca, 0xfe]));
+    }
+
+    #[test]
+    fn check_bytes_descriptor_test() {
+        let mut builder = TypeDescBuilder::default();
+        let base = builder.base_scalar(STD_BYTES);
+        builder.scalar(base);
+        let desc = builder.build();
+
+        assert!(Bytes::check_descriptor(&desc.as_queryable_context(), desc.root_pos().unwrap()).is_ok());
+
+        let mut builder = TypeDescBuilder::default();
+        builder.base_scalar(STD_STR);
+        let desc = builder.build();
+
+        assert!(Bytes::check_descriptor(&desc.as_queryable_context(), desc.root_pos().unwrap()).is_err());
+    }
+
+    #[test]
+    fn bytes_crate_test() {
+        let bytes = bytes::Bytes::from_static(&[0xca, 0xfe]);
+
+        assert_eq!(bytes::Bytes::scalar(), "<bytes>");
+        assert_eq!(bytes.to_edge_value(), Value::Bytes(vec![0xca, 0xfe]));
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(all(test, feature = "chrono"))]
+mod chrono_tests {
+    use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Utc};
+    use edgedb_protocol::value::Value;
+    use edgedb_query::{ToEdgeScalar, ToEdgeValue, ValueOverflow};
+
+    #[test]
+    fn datetime_utc_round_trip_test() {
+        let datetime = Utc.with_ymd_and_hms(2023, 5, 17, 10, 30, 0).unwrap();
+
+        assert_eq!(DateTime::<Utc>::scalar(), "<datetime>");
+
+        match datetime.to_edge_value() {
+            Value::Datetime(v) => assert_eq!(DateTime::<Utc>::try_from(v).unwrap(), datetime),
+            v => panic!("Expected a datetime value, got {v:?}")
+        }
+    }
+
+    #[test]
+    fn datetime_local_round_trip_test() {
+        let datetime = Local.with_ymd_and_hms(2023, 5, 17, 10, 30, 0).unwrap();
+
+        assert_eq!(DateTime::<Local>::scalar(), "<datetime>");
+
+        match datetime.to_edge_value() {
+            Value::Datetime(v) => assert_eq!(DateTime::<Utc>::try_from(v).unwrap(), datetime.with_timezone(&Utc)),
+            v => panic!("Expected a datetime value, got {v:?}")
+        }
+    }
+
+    #[test]
+    fn naive_datetime_round_trip_test() {
+        let datetime = NaiveDate::from_ymd_opt(2023, 5, 17).unwrap().and_hms_opt(10, 30, 0).unwrap();
+
+        assert_eq!(NaiveDateTime::scalar(), "<cal::local_datetime>");
+
+        match datetime.to_edge_value() {
+            Value::LocalDatetime(v) => assert_eq!(NaiveDateTime::try_from(v).unwrap(), datetime),
+            v => panic!("Expected a local datetime value, got {v:?}")
+        }
+    }
+
+    #[test]
+    fn naive_date_round_trip_test() {
+        let date = NaiveDate::from_ymd_opt(2023, 5, 17).unwrap();
+
+        assert_eq!(NaiveDate::scalar(), "<cal::local_date>");
+
+        match date.to_edge_value() {
+            Value::LocalDate(v) => assert_eq!(NaiveDate::try_from(v).unwrap(), date),
+            v => panic!("Expected a local date value, got {v:?}")
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn date_local_round_trip_test() {
+        let date = Local.ymd(2023, 5, 17);
+
+        assert_eq!(chrono::Date::<Local>::scalar(), "<cal::local_date>");
+
+        match date.to_edge_value() {
+            Value::LocalDate(v) => assert_eq!(NaiveDate::try_from(v).unwrap(), date.naive_local()),
+            v => panic!("Expected a local date value, got {v:?}")
+        }
+    }
+
+    #[test]
+    fn naive_time_round_trip_test() {
+        let time = NaiveTime::from_hms_micro_opt(10, 30, 15, 250).unwrap();
+
+        assert_eq!(NaiveTime::scalar(), "<cal::local_time>");
+
+        match time.to_edge_value() {
+            Value::LocalTime(v) => assert_eq!(NaiveTime::from(v), time),
+            v => panic!("Expected a local time value, got {v:?}")
+        }
+    }
+
+    #[test]
+    fn duration_round_trip_test() {
+        let duration = Duration::hours(2) + Duration::microseconds(42);
+
+        assert_eq!(Duration::scalar(), "<duration>");
+
+        match duration.to_edge_value() {
+            Value::Duration(v) => assert_eq!(v.to_micros(), duration.num_microseconds().unwrap()),
+            v => panic!("Expected a duration value, got {v:?}")
+        }
+    }
+
+    #[test]
+    fn duration_overflow_test() {
+        let duration = TimeDelta::MAX;
+
+        assert_eq!(duration.try_to_edge_value(), Err(ValueOverflow::new(duration, "duration")));
+    }
+
+    #[test]
+    fn naive_date_overflow_test() {
+        let date = NaiveDate::MIN;
+
+        assert_eq!(date.try_to_edge_value(), Err(ValueOverflow::new(date, "cal::local_date")));
+    }
+
+    #[test]
+    fn datetime_overflow_test() {
+        let datetime = DateTime::<Utc>::MIN_UTC;
+
+        assert_eq!(datetime.try_to_edge_value(), Err(ValueOverflow::new(datetime, "datetime")));
+        assert_eq!(NaiveDateTime::MIN.try_to_edge_value(), Err(ValueOverflow::new(NaiveDateTime::MIN, "cal::local_datetime")));
+    }
+}
@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod durations_tests {
+    use edgedb_protocol::model::{DateDuration, RelativeDuration};
+    use edgedb_protocol::value::Value;
+    use edgedb_query::{ToEdgeScalar, ToEdgeValue};
+
+    #[test]
+    fn relative_duration_test() {
+        let duration = RelativeDuration::from_months(1) + RelativeDuration::from_days(2) + RelativeDuration::from_hours(3);
+
+        assert_eq!(RelativeDuration::scalar(), "<cal::relative_duration>");
+        assert_eq!(duration.to_edge_value(), Value::RelativeDuration(duration));
+    }
+
+    #[test]
+    fn date_duration_test() {
+        let duration = DateDuration::from_months(1) + DateDuration::from_days(15);
+
+        assert_eq!(DateDuration::scalar(), "<cal::date_duration>");
+        assert_eq!(duration.to_edge_value(), Value::DateDuration(duration));
+    }
+}
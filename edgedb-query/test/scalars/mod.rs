@@ -0,0 +1,7 @@
+mod bignum;
+mod bytes;
+mod chrono;
+mod durations;
+mod scalar_types;
+mod time;
+mod unsigned;
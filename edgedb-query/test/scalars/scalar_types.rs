@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod scalar_types_tests {
+    use edgedb_protocol::model::{BigInt, DateDuration, Decimal, RelativeDuration};
+    use edgedb_protocol::value::Value;
+    use edgedb_query::{Bytes, Link, Links, ToEdgeScalar, ToEdgeValue};
+    use uuid::Uuid;
+
+    /// Check that the scalar cast of a type and the variant of the value it encodes to agree
+    macro_rules! assert_scalar {
+        ($value: expr, $ty: ty, $scalar: expr, $variant: pat) => {
+            assert_eq!(<$ty>::scalar(), $scalar);
+            assert!(matches!($value.to_edge_value(), $variant), "{} does not encode to {}", stringify!($ty), $scalar);
+        };
+    }
+
+    #[test]
+    fn all_scalar_types_test() {
+        assert_scalar!(String::from("joe"), String, "<str>", Value::Str(_));
+        assert_scalar!(1_i8, i8, "<int16>", Value::Int16(_));
+        assert_scalar!(1_u8, u8, "<int16>", Value::Int16(_));
+        assert_scalar!(1_i16, i16, "<int16>", Value::Int16(_));
+        assert_scalar!(1_u16, u16, "<int32>", Value::Int32(_));
+        assert_scalar!(1_i32, i32, "<int32>", Value::Int32(_));
+        assert_scalar!(1_u32, u32, "<int64>", Value::Int64(_));
+        assert_scalar!(1_i64, i64, "<int64>", Value::Int64(_));
+        assert_scalar!(1_u64, u64, "<int64>", Value::Int64(_));
+        assert_scalar!(1.0_f32, f32, "<float32>", Value::Float32(_));
+        assert_scalar!(1.0_f64, f64, "<float64>", Value::Float64(_));
+        assert_scalar!(true, bool, "<bool>", Value::Bool(_));
+        assert_scalar!(Uuid::new_v4(), Uuid, "<uuid>", Value::Uuid(_));
+        assert_scalar!(serde_json::json!({"a": 1}), serde_json::Value, "<json>", Value::Json(_));
+        assert_scalar!(Bytes::from(vec![1]), Bytes, "<bytes>", Value::Bytes(_));
+        assert_scalar!(BigInt::from(1i64), BigInt, "<bigint>", Value::BigInt(_));
+        assert_eq!(Decimal::scalar(), "<decimal>");
+        assert_scalar!(RelativeDuration::from_months(2), RelativeDuration, "<cal::relative_duration>", Value::RelativeDuration(_));
+        assert_scalar!(DateDuration::from_days(3), DateDuration, "<cal::date_duration>", Value::DateDuration(_));
+        assert_eq!(Link::<()>::scalar(), "<uuid>");
+        assert_eq!(Links::<()>::scalar(), "<array<uuid>>");
+        assert_eq!(<()>::scalar(), "");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_scalar_types_test() {
+        use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+
+        assert_scalar!(Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(), DateTime<Utc>, "<datetime>", Value::Datetime(_));
+        assert_scalar!(Local.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(), DateTime<Local>, "<datetime>", Value::Datetime(_));
+        assert_scalar!(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(), NaiveDateTime, "<cal::local_datetime>", Value::LocalDatetime(_));
+        assert_scalar!(Duration::seconds(1), Duration, "<duration>", Value::Duration(_));
+        assert_scalar!(NaiveTime::from_hms_opt(1, 0, 0).unwrap(), NaiveTime, "<cal::local_time>", Value::LocalTime(_));
+        assert_scalar!(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(), NaiveDate, "<cal::local_date>", Value::LocalDate(_));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_scalar_types_test() {
+        use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time};
+
+        let date = Date::from_calendar_date(2023, Month::January, 1).unwrap();
+
+        assert_scalar!(OffsetDateTime::UNIX_EPOCH, OffsetDateTime, "<datetime>", Value::Datetime(_));
+        assert_scalar!(PrimitiveDateTime::new(date, Time::MIDNIGHT), PrimitiveDateTime, "<cal::local_datetime>", Value::LocalDatetime(_));
+        assert_scalar!(Duration::seconds(1), Duration, "<duration>", Value::Duration(_));
+        assert_scalar!(Time::MIDNIGHT, Time, "<cal::local_time>", Value::LocalTime(_));
+        assert_scalar!(date, Date, "<cal::local_date>", Value::LocalDate(_));
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(all(test, feature = "time"))]
+mod time_tests {
+    use edgedb_protocol::model::{Datetime, Duration as EdgeDuration, LocalDate, LocalDatetime, LocalTime};
+    use edgedb_protocol::value::Value;
+    use edgedb_query::{ToEdgeScalar, ToEdgeValue, ValueOverflow};
+    use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time};
+
+    fn date() -> Date {
+        Date::from_calendar_date(2023, Month::May, 17).unwrap()
+    }
+
+    fn time() -> Time {
+        Time::from_hms_micro(10, 30, 15, 250).unwrap()
+    }
+
+    #[test]
+    fn date_test() {
+        assert_eq!(Date::scalar(), "<cal::local_date>");
+        assert_eq!(date().to_edge_value(), Value::LocalDate(LocalDate::from_ymd(2023, 5, 17)));
+    }
+
+    #[test]
+    fn time_test() {
+        assert_eq!(Time::scalar(), "<cal::local_time>");
+        assert_eq!(time().to_edge_value(), Value::LocalTime(LocalTime::from_micros(37_815_000_250)));
+    }
+
+    #[test]
+    fn primitive_datetime_test() {
+        let expected = LocalDatetime::new(LocalDate::from_ymd(2023, 5, 17), LocalTime::from_micros(37_815_000_250));
+
+        assert_eq!(PrimitiveDateTime::scalar(), "<cal::local_datetime>");
+        assert_eq!(PrimitiveDateTime::new(date(), time()).to_edge_value(), Value::LocalDatetime(expected));
+    }
+
+    #[test]
+    fn offset_datetime_test() {
+        assert_eq!(OffsetDateTime::scalar(), "<datetime>");
+        assert!(matches!(OffsetDateTime::UNIX_EPOCH.to_edge_value(), Value::Datetime(_)));
+    }
+
+    #[test]
+    fn duration_test() {
+        assert_eq!(Duration::scalar(), "<duration>");
+        assert_eq!(Duration::minutes(90).to_edge_value(), Value::Duration(EdgeDuration::from_micros(5_400_000_000)));
+    }
+
+    #[test]
+    fn duration_overflow_test() {
+        assert_eq!(Duration::MAX.try_to_edge_value(), Err(ValueOverflow::new(Duration::MAX, "duration")));
+    }
+
+    #[test]
+    fn date_overflow_test() {
+        assert_eq!(Date::MIN.try_to_edge_value(), Err(ValueOverflow::new(Date::MIN, "cal::local_date")));
+    }
+
+    #[test]
+    fn offset_datetime_value_test() {
+        let datetime = OffsetDateTime::UNIX_EPOCH + Duration::microseconds(1_500);
+
+        match datetime.to_edge_value() {
+            Value::Datetime(v) => assert_eq!(v.to_micros(), Datetime::UNIX_EPOCH.to_micros() + 1_500),
+            v => panic!("Expected a datetime value, got {v:?}")
+        }
+    }
+}
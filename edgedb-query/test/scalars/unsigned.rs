@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod unsigned_tests {
+    use edgedb_protocol::value::Value;
+    use edgedb_query::{ToEdgeScalar, ToEdgeValue, ValueOverflow};
+
+    #[test]
+    fn u16_test() {
+        assert_eq!(u16::scalar(), "<int32>");
+        assert_eq!(u16::MAX.to_edge_value(), Value::Int32(65_535));
+    }
+
+    #[test]
+    fn u32_test() {
+        assert_eq!(u32::scalar(), "<int64>");
+        assert_eq!(u32::MAX.to_edge_value(), Value::Int64(4_294_967_295));
+    }
+
+    #[test]
+    fn u64_test() {
+        assert_eq!(u64::scalar(), "<int64>");
+        assert_eq!((i64::MAX as u64).try_to_edge_value(), Ok(Value::Int64(i64::MAX)));
+        assert_eq!(u64::MAX.try_to_edge_value(), Err(ValueOverflow::new(u64::MAX, "int64")));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows the edgeDB int64 type")]
+    fn u64_overflow_test() {
+        u64::MAX.to_edge_value();
+    }
+
+    #[test]
+    fn vec_and_option_overflow_test() {
+        assert_eq!(vec![1_u64, u64::MAX].try_to_edge_value(), Err(ValueOverflow::new(u64::MAX, "int64")));
+        assert_eq!(Some(u64::MAX).try_to_edge_value(), Err(ValueOverflow::new(u64::MAX, "int64")));
+        assert_eq!(None::<u64>.try_to_edge_value(), Ok(Value::Nothing));
+    }
+}
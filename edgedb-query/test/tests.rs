@@ -1 +1,3 @@
-mod queries;
\ No newline at end of file
+mod queries;
+mod models;
+mod scalars;
@@ -3,7 +3,7 @@
 mod tests {
 
     use rstest::*;
-    use edgedb_query::BasicResult;
+    use edgedb_query::{BasicResult, InsertMany};
     use edgedb_query_derive::{delete_query, insert_query};
     use edgedb_query::models::edge_query::{EdgeQuery, ToEdgeQuery};
 
@@ -53,19 +53,17 @@ mod tests {
             },
         ];
 
-        for city in cities {
-            let edge_query: EdgeQuery = city.to_edge_query();
+        let edge_query: EdgeQuery = InsertMany::new(cities).to_edge_query();
 
-            let args = &edge_query.args.unwrap();
+        let args = &edge_query.args.unwrap();
 
-            let query = edge_query.query.as_str();
+        let query = edge_query.query.as_str();
 
-            if let Some(json) = client.query_single::<BasicResult, _>(query, args).await.unwrap() {
-                assert_ne!(json.id.to_string(), String::default());
-            } else {
-                unreachable!()
-            }
-        }
+        let inserted = client.query::<BasicResult, _>(query, args).await.unwrap();
+
+        assert_eq!(inserted.len(), 3);
+
+        assert!(inserted.iter().all(|city| city.id.to_string() != String::default()));
 
 
         let count = client.query_required_single_json("select count((select City))", &()).await.unwrap();